@@ -0,0 +1,470 @@
+//! 账号备份与恢复
+//!
+//! 备份是一个目录而不是单个压缩包，文件内容可以边下载边写入、恢复时逐个读取，
+//! 不需要把整个账号的数据放进内存；需要单个文件时可以自行用 tar/zip 打包。
+//! 格式版本记录在 `manifest.json` 中，目录结构：
+//! - `manifest.json`：格式版本 [`BACKUP_VERSION`]、备份时间与各资源数量
+//! - `<资源名称>.json`：该资源的全部记录，名称见 [`Resource::NAME`]
+//! - `files/<文件 id>`：活码文件的原始内容
+//!
+//! 恢复时按 文件 → 活码 → 其它资源 的顺序创建，活码的 `fid` 会替换为新文件的 id。
+//! 新旧 id 的对应关系在 [`RestoreReport::ids`] 中。
+//!
+//! 读写文件使用 `tokio::fs`，需要在 tokio 运行时中调用。
+//!
+//! ```no_run
+//! use vxwk_rs_sdk::{BatchOptions, VxwkAPI, VxwkError};
+//!
+//! # async fn run(from: VxwkAPI, to: VxwkAPI) -> Result<(), VxwkError> {
+//! from.backup("backup-2024-03", BatchOptions::new()).await?;
+//! let report = to.restore("backup-2024-03", BatchOptions::new()).await?;
+//! for failure in &report.failures {
+//!     eprintln!("{} {}: {}", failure.resource, failure.old_id, failure.error);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::batch::{self, BatchOptions};
+use crate::model::dycard::{CreateDyCard, DyCard};
+use crate::model::external::{CreateExternalLink, ExternalLink};
+use crate::model::livecode::{CreateLiveCode, LiveCode};
+use crate::model::livecodefile::{CreateLiveCodeFile, LiveCodeFile, UploadedFile};
+use crate::model::shortlink::{CreateShortLink, ShortLink};
+use crate::model::wxcard::{CreateWxCard, WxCard};
+use crate::model::ApiResponse;
+use crate::paginate::PageOptions;
+use crate::resource::{
+    self, DyCardResource, ExternalLinkResource, LiveCodeFileResource, LiveCodeResource, Resource,
+    ShortLinkResource, WxCardResource,
+};
+use crate::{VxwkAPI, VxwkError};
+use futures::TryStreamExt;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+/// 当前的备份格式版本
+pub const BACKUP_VERSION: u32 = 1;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const FILES_DIR: &str = "files";
+/// 导出时每页拉取的条数
+const EXPORT_PAGE_SIZE: u32 = 100;
+
+/// 备份的描述信息
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupManifest {
+    pub version: u32,
+    /// 备份时间，秒级时间戳
+    pub created_at: i64,
+    /// 各资源的记录数，键为资源名称
+    pub counts: BTreeMap<String, usize>,
+}
+
+/// 恢复失败的记录
+#[derive(Debug)]
+pub struct RestoreFailure {
+    pub resource: &'static str,
+    /// 备份中的 id
+    pub old_id: String,
+    pub error: VxwkError,
+}
+
+/// 恢复结果
+#[derive(Debug, Default)]
+pub struct RestoreReport {
+    /// 资源名称 → (旧 id → 新 id)
+    pub ids: BTreeMap<&'static str, BTreeMap<String, String>>,
+    pub failures: Vec<RestoreFailure>,
+}
+
+impl RestoreReport {
+    pub fn is_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// 查询某条记录恢复后的新 id
+    pub fn new_id(&self, resource: &str, old_id: &str) -> Option<&str> {
+        self.ids.get(resource)?.get(old_id).map(String::as_str)
+    }
+}
+
+/// 可以从备份中恢复的资源
+pub trait Restore: Resource {
+    /// 创建接口返回的新 id
+    fn created_id(created: &Self::Created) -> &str;
+}
+
+impl Restore for ShortLinkResource {
+    fn created_id(created: &ShortLink) -> &str {
+        &created.id
+    }
+}
+
+impl Restore for ExternalLinkResource {
+    fn created_id(created: &ExternalLink) -> &str {
+        &created.id
+    }
+}
+
+impl Restore for DyCardResource {
+    fn created_id(created: &DyCard) -> &str {
+        &created.id
+    }
+}
+
+impl Restore for WxCardResource {
+    fn created_id(created: &WxCard) -> &str {
+        &created.id
+    }
+}
+
+impl Restore for LiveCodeResource {
+    fn created_id(created: &LiveCode) -> &str {
+        &created.id
+    }
+}
+
+impl Restore for LiveCodeFileResource {
+    fn created_id(created: &UploadedFile) -> &str {
+        &created.id
+    }
+}
+
+impl VxwkAPI {
+    /// 导出所有资源与活码文件内容到目录 `dir`，目录不存在时会创建
+    ///
+    /// 文件下载的并发数由 `opt` 控制；任意一步失败都会返回错误，此时目录中的内容不完整
+    pub async fn backup(
+        &self,
+        dir: impl AsRef<Path>,
+        opt: BatchOptions,
+    ) -> Result<BackupManifest, VxwkError> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir.join(FILES_DIR)).await?;
+
+        let mut counts = BTreeMap::new();
+        export::<ShortLinkResource>(self, dir, &mut counts).await?;
+        export::<ExternalLinkResource>(self, dir, &mut counts).await?;
+        export::<DyCardResource>(self, dir, &mut counts).await?;
+        export::<WxCardResource>(self, dir, &mut counts).await?;
+        export::<LiveCodeResource>(self, dir, &mut counts).await?;
+        let files = export::<LiveCodeFileResource>(self, dir, &mut counts).await?;
+
+        let downloads = batch::run(&files, opt, |file| async move {
+            self.download(&file.url, &file_path(dir, &file.id)?).await
+        })
+        .await;
+        downloads.into_iter().collect::<Result<Vec<_>, _>>()?;
+
+        let manifest = BackupManifest {
+            version: BACKUP_VERSION,
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or_default(),
+            counts,
+        };
+        write_json(dir, MANIFEST_FILE, &manifest).await?;
+        Ok(manifest)
+    }
+
+    /// 从目录 `dir` 中的备份重新创建全部资源，可以恢复到同一个或另一个账号
+    ///
+    /// 单条记录失败不会中断恢复，失败的记录在 [`RestoreReport::failures`] 中；
+    /// 引用了未恢复文件的活码保留原本的 `fid`
+    pub async fn restore(
+        &self,
+        dir: impl AsRef<Path>,
+        opt: BatchOptions,
+    ) -> Result<RestoreReport, VxwkError> {
+        let dir = dir.as_ref();
+        let manifest: BackupManifest = read_json(dir, MANIFEST_FILE).await?;
+        if manifest.version > BACKUP_VERSION {
+            return Err(VxwkError::InvalidRequest(format!(
+                "unsupported backup version {}, expected at most {}",
+                manifest.version, BACKUP_VERSION
+            )));
+        }
+
+        let mut report = RestoreReport::default();
+        let files: Vec<LiveCodeFile> = read_models::<LiveCodeFileResource>(dir).await?;
+        import_files(self, dir, files, opt, &mut report).await;
+
+        let codes = read_models::<LiveCodeResource>(dir).await?;
+        let reqs = codes
+            .into_iter()
+            .map(|code| {
+                let mut req = live_code_req(&code);
+                req.file_id = req.file_id.map(|fid| {
+                    report
+                        .new_id(LiveCodeFileResource::NAME, &fid)
+                        .map(str::to_string)
+                        .unwrap_or(fid)
+                });
+                (code.id, req)
+            })
+            .collect();
+        import::<LiveCodeResource>(self, reqs, opt, &mut report).await;
+
+        let reqs = read_reqs::<ShortLinkResource>(dir, short_link_req).await?;
+        import::<ShortLinkResource>(self, reqs, opt, &mut report).await;
+        let reqs = read_reqs::<ExternalLinkResource>(dir, external_link_req).await?;
+        import::<ExternalLinkResource>(self, reqs, opt, &mut report).await;
+        let reqs = read_reqs::<DyCardResource>(dir, dy_card_req).await?;
+        import::<DyCardResource>(self, reqs, opt, &mut report).await;
+        let reqs = read_reqs::<WxCardResource>(dir, wx_card_req).await?;
+        import::<WxCardResource>(self, reqs, opt, &mut report).await;
+        Ok(report)
+    }
+
+    /// 下载文件内容并逐块写入 `path`，文件地址不需要签名
+    async fn download(&self, url: &str, path: &Path) -> Result<(), VxwkError> {
+        let mut response = self.client.get(url).send().await?.error_for_status()?;
+        let mut file = fs::File::create(path).await?;
+        while let Some(chunk) = response.chunk().await? {
+            file.write_all(&chunk).await?;
+        }
+        file.flush().await?;
+        Ok(())
+    }
+}
+
+/// 拉取资源 `R` 的全部记录并写入 `<NAME>.json`
+async fn export<R>(
+    api: &VxwkAPI,
+    dir: &Path,
+    counts: &mut BTreeMap<String, usize>,
+) -> Result<Vec<R::Model>, VxwkError>
+where
+    R: Resource,
+    R::Model: Serialize,
+    R::Filter: Default,
+{
+    let page = PageOptions::new().with_page_size(EXPORT_PAGE_SIZE);
+    let models: Vec<R::Model> = resource::stream::<R>(api, R::Filter::default(), page)
+        .try_collect()
+        .await?;
+    write_json(dir, &format!("{}.json", R::NAME), &models).await?;
+    counts.insert(R::NAME.to_string(), models.len());
+    Ok(models)
+}
+
+/// 逐条创建，记录新旧 id
+async fn import<R: Restore>(
+    api: &VxwkAPI,
+    reqs: Vec<(String, R::Create)>,
+    opt: BatchOptions,
+    report: &mut RestoreReport,
+) {
+    let results = batch::run(&reqs, opt, |(_, req)| resource::create::<R>(api, req)).await;
+    record::<R>(report, reqs.into_iter().map(|(old_id, _)| old_id), results);
+}
+
+/// 逐个读取文件内容并上传，内存中最多同时保留并发数个文件
+async fn import_files(
+    api: &VxwkAPI,
+    dir: &Path,
+    files: Vec<LiveCodeFile>,
+    opt: BatchOptions,
+    report: &mut RestoreReport,
+) {
+    let results = batch::run(&files, opt, |file| async move {
+        let content = fs::read(file_path(dir, &file.id)?).await?;
+        let req = CreateLiveCodeFile::new(&file.name, content);
+        resource::create::<LiveCodeFileResource>(api, &req).await
+    })
+    .await;
+    record::<LiveCodeFileResource>(report, files.into_iter().map(|file| file.id), results);
+}
+
+/// 记录新旧 id 与失败的记录
+fn record<R: Restore>(
+    report: &mut RestoreReport,
+    old_ids: impl Iterator<Item = String>,
+    results: Vec<Result<ApiResponse<R::Created>, VxwkError>>,
+) {
+    let ids = report.ids.entry(R::NAME).or_default();
+    for (old_id, result) in old_ids.zip(results) {
+        match result {
+            Ok(res) => {
+                ids.insert(old_id, R::created_id(&res.data).to_string());
+            }
+            Err(error) => report.failures.push(RestoreFailure {
+                resource: R::NAME,
+                old_id,
+                error,
+            }),
+        }
+    }
+}
+
+/// 读取 `<NAME>.json`，文件不存在时按空处理
+async fn read_models<R>(dir: &Path) -> Result<Vec<R::Model>, VxwkError>
+where
+    R: Resource,
+{
+    match read_json(dir, &format!("{}.json", R::NAME)).await {
+        Err(VxwkError::IoError(err)) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        models => models,
+    }
+}
+
+async fn read_reqs<R>(
+    dir: &Path,
+    to_req: fn(&R::Model) -> R::Create,
+) -> Result<Vec<(String, R::Create)>, VxwkError>
+where
+    R: Resource,
+{
+    Ok(read_models::<R>(dir)
+        .await?
+        .iter()
+        .map(|model| (R::id(model).to_string(), to_req(model)))
+        .collect())
+}
+
+async fn read_json<T: DeserializeOwned>(dir: &Path, name: &str) -> Result<T, VxwkError> {
+    let content = fs::read(dir.join(name)).await?;
+    serde_json::from_slice(&content).map_err(|err| {
+        VxwkError::InvalidRequest(format!("invalid backup file `{}`: {}", name, err))
+    })
+}
+
+async fn write_json<T: Serialize + ?Sized>(
+    dir: &Path,
+    name: &str,
+    value: &T,
+) -> Result<(), VxwkError> {
+    let content = serde_json::to_vec_pretty(value)
+        .map_err(|err| VxwkError::InvalidResponse(err.to_string()))?;
+    fs::write(dir.join(name), content).await?;
+    Ok(())
+}
+
+/// 文件 id 直接作为文件名，拒绝可能跳出备份目录的 id
+fn file_path(dir: &Path, id: &str) -> Result<std::path::PathBuf, VxwkError> {
+    let valid = !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(VxwkError::InvalidResponse(format!(
+            "unexpected file id `{}`",
+            id
+        )));
+    }
+    Ok(dir.join(FILES_DIR).join(id))
+}
+
+/// 空字符串按未设置处理
+fn non_empty(value: &str) -> Option<String> {
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+fn short_link_req(link: &ShortLink) -> CreateShortLink {
+    CreateShortLink {
+        domain_id: link.domain_id.clone(),
+        link: link.link.clone(),
+        hash: non_empty(&link.hash),
+        title: non_empty(&link.title),
+        describe: non_empty(&link.describe),
+    }
+}
+
+fn external_link_req(link: &ExternalLink) -> CreateExternalLink {
+    CreateExternalLink {
+        domain_id: link.domain_id.clone(),
+        title: link.title.clone(),
+        describe: link.describe.clone(),
+        tips: link.tips.clone(),
+        img: link.img.clone(),
+        link_type: link.link_type,
+        start_at: link.start_at,
+        stop_at: link.stop_at,
+        link: link.link.clone(),
+        hash: link.hash.clone(),
+        test_mode: link.test_mode,
+        style: link.style.clone(),
+    }
+}
+
+fn dy_card_req(card: &DyCard) -> CreateDyCard {
+    CreateDyCard {
+        title: card.title.clone(),
+        link: card.link.clone(),
+        describe: non_empty(&card.describe),
+        img: non_empty(&card.img),
+    }
+}
+
+fn wx_card_req(card: &WxCard) -> CreateWxCard {
+    CreateWxCard {
+        title: card.title.clone(),
+        link: card.link.clone(),
+        describe: non_empty(&card.describe),
+        img: non_empty(&card.img),
+    }
+}
+
+fn live_code_req(code: &LiveCode) -> CreateLiveCode {
+    CreateLiveCode {
+        name: code.name.clone(),
+        describe: non_empty(&code.describe),
+        file_id: code.file_id.clone(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{file_path, read_models, short_link_req, write_json, BackupManifest};
+    use crate::model::shortlink::ShortLink;
+    use crate::resource::ShortLinkResource;
+    use serde_json::json;
+    use std::path::Path;
+
+    #[tokio::test]
+    async fn test_short_link_round_trip() {
+        let dir = std::env::temp_dir().join(format!("vxwk-backup-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let link: ShortLink = serde_json::from_value(json!({
+            "id": "1", "domainID": "d", "link": "https://a.com", "hash": "spring", "title": "", "pv": 3
+        }))
+        .unwrap();
+        write_json(&dir, "short_link.json", &[link]).await.unwrap();
+
+        let links = read_models::<ShortLinkResource>(&dir).await.unwrap();
+        assert_eq!(links[0].extra["pv"], 3);
+        let req = short_link_req(&links[0]);
+        assert_eq!(req.hash.as_deref(), Some("spring"));
+        assert_eq!(req.title, None);
+        // 缺少的资源文件按空处理
+        assert!(read_models::<crate::resource::DyCardResource>(&dir)
+            .await
+            .unwrap()
+            .is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_reject_unsafe_file_id() {
+        assert!(file_path(Path::new("b"), "9f2c-01_a").is_ok());
+        assert!(file_path(Path::new("b"), "../etc").is_err());
+        assert!(file_path(Path::new("b"), "").is_err());
+    }
+
+    #[test]
+    fn test_manifest_format() {
+        let manifest: BackupManifest = serde_json::from_value(
+            json!({"version": 1, "createdAt": 1700000000, "counts": {"short_link": 2}}),
+        )
+        .unwrap();
+        assert_eq!(manifest.counts["short_link"], 2);
+    }
+}
@@ -0,0 +1,121 @@
+//! 批量请求
+use crate::VxwkError;
+use futures::stream::{self, StreamExt};
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 默认同时进行的请求数
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 4;
+
+/// 批量请求的选项
+/// ```
+/// use vxwk_rs_sdk::BatchOptions;
+///
+/// // 最多同时 8 个请求，遇到第一个错误后不再发起新的请求
+/// let opt = BatchOptions::new().with_concurrency(8).with_stop_on_error(true);
+/// assert!(opt.stop_on_error);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchOptions {
+    pub concurrency: usize,
+    /// 遇到错误后跳过尚未开始的请求，已发出的请求仍会等待完成
+    pub stop_on_error: bool,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: DEFAULT_BATCH_CONCURRENCY,
+            stop_on_error: false,
+        }
+    }
+}
+
+impl BatchOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn with_stop_on_error(mut self, stop_on_error: bool) -> Self {
+        self.stop_on_error = stop_on_error;
+        self
+    }
+}
+
+/// 并发执行 `f`，按输入顺序返回每一项的结果
+///
+/// 开启 `stop_on_error` 后，出错之后才开始的项返回 [`VxwkError::Skipped`]
+pub(crate) async fn run<I, T, F, Fut>(
+    items: I,
+    opt: BatchOptions,
+    f: F,
+) -> Vec<Result<T, VxwkError>>
+where
+    I: IntoIterator,
+    F: Fn(I::Item) -> Fut,
+    Fut: Future<Output = Result<T, VxwkError>>,
+{
+    let failed = AtomicBool::new(false);
+    let failed = &failed;
+    let f = &f;
+    stream::iter(items)
+        .map(|item| async move {
+            if opt.stop_on_error && failed.load(Ordering::SeqCst) {
+                return Err(VxwkError::Skipped);
+            }
+            let result = f(item).await;
+            if result.is_err() {
+                failed.store(true, Ordering::SeqCst);
+            }
+            result
+        })
+        .buffered(opt.concurrency.max(1))
+        .collect()
+        .await
+}
+
+#[cfg(test)]
+mod test {
+    use super::{run, BatchOptions};
+    use crate::VxwkError;
+
+    fn check(n: u32) -> Result<u32, VxwkError> {
+        if n == 3 || n == 6 {
+            Err(VxwkError::InvalidRequest(n.to_string()))
+        } else {
+            Ok(n * 10)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_results_in_input_order() {
+        let opt = BatchOptions::new().with_concurrency(3);
+        let results = run(1..=6, opt, |n| async move {
+            // 越靠前的项完成得越晚
+            for _ in 0..(6 - n) {
+                tokio::task::yield_now().await;
+            }
+            check(n)
+        })
+        .await;
+        let ok: Vec<_> = results.iter().map(|r| r.as_ref().ok().copied()).collect();
+        assert_eq!(ok, [Some(10), Some(20), None, Some(40), Some(50), None]);
+    }
+
+    #[tokio::test]
+    async fn test_stop_on_error() {
+        let opt = BatchOptions::new()
+            .with_concurrency(1)
+            .with_stop_on_error(true);
+        let results = run(1..=5, opt, |n| async move { check(n) }).await;
+        assert!(results[1].is_ok());
+        assert!(matches!(results[2], Err(VxwkError::InvalidRequest(_))));
+        assert!(matches!(results[3], Err(VxwkError::Skipped)));
+        assert!(matches!(results[4], Err(VxwkError::Skipped)));
+    }
+}
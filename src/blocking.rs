@@ -0,0 +1,1448 @@
+//! 同步版本的客户端，需要开启 `blocking` feature
+//!
+//! 内部持有一个单线程 tokio 运行时，签名、节点切换与模型均复用 [`VxwkAPI`]。
+//! 不要在异步上下文中调用，否则运行时会 panic。
+#[cfg(feature = "backup")]
+use crate::backup::{BackupManifest, RestoreReport};
+#[cfg(any(
+    feature = "dy_card",
+    feature = "wx_card",
+    feature = "live_code",
+    feature = "live_code_file",
+    feature = "external",
+    feature = "short_link"
+))]
+use crate::batch::BatchOptions;
+#[cfg(feature = "dy_card")]
+use crate::model::dycard::{CreateDyCard, DyCard, DyCardListFilter, UpdateDyCard};
+#[cfg(feature = "external")]
+use crate::model::external::{
+    CreateExternalLink, ExternalLink, ExternalLinkListFilter, UpdateExternalLink,
+};
+#[cfg(feature = "live_code")]
+use crate::model::livecode::{CreateLiveCode, LiveCode, LiveCodeListFilter, UpdateLiveCode};
+#[cfg(feature = "live_code_file")]
+use crate::model::livecodefile::{
+    CreateLiveCodeFile, LiveCodeFile, LiveCodeFileListFilter, UpdateLiveCodeFile, UploadOptions,
+    UploadedFile,
+};
+#[cfg(feature = "short_link")]
+use crate::model::shortlink::{CreateShortLink, ShortLink, ShortLinkListFilter, UpdateShortLink};
+#[cfg(feature = "wx_card")]
+use crate::model::wxcard::{CreateWxCard, UpdateWxCard, WxCard, WxCardListFilter};
+#[cfg(feature = "short_link")]
+use crate::model::FindOrCreate;
+#[cfg(any(
+    feature = "dy_card",
+    feature = "wx_card",
+    feature = "live_code",
+    feature = "live_code_file",
+    feature = "external",
+    feature = "short_link"
+))]
+use crate::model::{ApiResponse, Page};
+use crate::paginate::ListStream;
+#[cfg(any(
+    feature = "dy_card",
+    feature = "wx_card",
+    feature = "live_code",
+    feature = "live_code_file",
+    feature = "external",
+    feature = "short_link"
+))]
+use crate::paginate::PageOptions;
+#[cfg(feature = "reconcile")]
+use crate::reconcile::{ApplyReport, DesiredState, Plan, PlanOptions, Progress};
+#[cfg(feature = "csv")]
+use crate::sheet::{ImportReport, SheetOptions};
+use crate::{NodeStatus, VxwkAPI, VxwkConfig, VxwkError};
+use futures::StreamExt;
+#[cfg(any(
+    feature = "dy_card",
+    feature = "wx_card",
+    feature = "live_code",
+    feature = "external",
+    feature = "short_link"
+))]
+use serde::Serialize;
+#[cfg(any(
+    feature = "dy_card",
+    feature = "wx_card",
+    feature = "live_code",
+    feature = "live_code_file",
+    feature = "external",
+    feature = "short_link"
+))]
+use std::collections::HashMap;
+#[cfg(feature = "csv")]
+use std::io::{Read, Write};
+#[cfg(any(feature = "backup", feature = "stream_upload"))]
+use std::path::Path;
+use tokio::runtime::{Builder, Runtime};
+
+/// 同步客户端，接口分组与 [`VxwkAPI`] 一一对应
+/// ```no_run
+/// # #[cfg(feature = "short_link")]
+/// # fn main() {
+/// use vxwk_rs_sdk::{ShortLinkListFilter, VxwkBlockingAPI, VxwkConfig};
+///
+/// let confg = VxwkConfig::new(
+///     "开发者access_key".to_string(),
+///     "开发者access_secret".to_owned(),
+///     "https://节点baseurl".to_string(),
+/// );
+/// let vxwk_api = VxwkBlockingAPI::new(confg).unwrap();
+/// let res = vxwk_api.short_links().list(&ShortLinkListFilter::new());
+/// println!("{:?}", res);
+/// # }
+/// # #[cfg(not(feature = "short_link"))]
+/// # fn main() {}
+/// ```
+pub struct VxwkBlockingAPI {
+    inner: VxwkAPI,
+    // 没有开启任何接口模块时只剩节点状态查询，运行时没有调用方
+    #[cfg_attr(
+        not(any(
+            feature = "dy_card",
+            feature = "wx_card",
+            feature = "live_code",
+            feature = "live_code_file",
+            feature = "external",
+            feature = "short_link"
+        )),
+        allow(dead_code)
+    )]
+    runtime: Runtime,
+}
+
+impl VxwkBlockingAPI {
+    pub fn new(config: VxwkConfig) -> Result<Self, VxwkError> {
+        let runtime = Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|err| VxwkError::OtherError(err.to_string()))?;
+        Ok(Self {
+            inner: VxwkAPI::new(config)?,
+            runtime,
+        })
+    }
+
+    /// 所有节点的健康状态
+    pub fn node_status(&self) -> Vec<NodeStatus> {
+        self.inner.node_status()
+    }
+
+    #[cfg(any(
+        feature = "dy_card",
+        feature = "wx_card",
+        feature = "live_code",
+        feature = "live_code_file",
+        feature = "external",
+        feature = "short_link"
+    ))]
+    fn iter<'a, T>(&'a self, stream: ListStream<'a, T>) -> BlockingListIter<'a, T> {
+        BlockingListIter {
+            runtime: &self.runtime,
+            stream,
+        }
+    }
+}
+
+/// 同步迭代分页流，每次迭代在需要时请求下一页
+pub struct BlockingListIter<'a, T> {
+    runtime: &'a Runtime,
+    stream: ListStream<'a, T>,
+}
+
+impl<T> Iterator for BlockingListIter<'_, T> {
+    type Item = Result<T, VxwkError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
+
+#[cfg(feature = "dy_card")]
+#[allow(deprecated)]
+impl VxwkBlockingAPI {
+    /// 抖音卡片图片地址
+    #[deprecated(note = "use `dy_cards().img_url()` instead")]
+    pub fn dy_card_img_url(
+        &self,
+        id: &str,
+        opt: Option<HashMap<&str, &str>>,
+    ) -> Result<String, VxwkError> {
+        self.runtime.block_on(self.inner.dy_card_img_url(id, opt))
+    }
+
+    /// 抖音卡片列表
+    #[deprecated(note = "use `dy_cards().list()` instead")]
+    pub fn dy_card_get_list(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.dy_card_get_list(opt))
+    }
+
+    /// 抖音卡片详情
+    #[deprecated(note = "use `dy_cards().get()` instead")]
+    pub fn dy_card_get_info(
+        &self,
+        id: &str,
+        opt: Option<HashMap<&str, &str>>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.dy_card_get_info(id, opt))
+    }
+
+    /// 抖音卡片创建
+    #[deprecated(note = "use `dy_cards().create()` instead")]
+    pub fn dy_card_create(&self, opt: HashMap<&str, &str>) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.dy_card_create(opt))
+    }
+
+    /// 抖音卡片修改
+    #[deprecated(note = "use `dy_cards().update()` instead")]
+    pub fn dy_card_update(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.dy_card_update(id, opt))
+    }
+
+    /// 抖音卡片删除
+    #[deprecated(note = "use `dy_cards().delete()` instead")]
+    pub fn dy_card_delete(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.dy_card_delete(id, opt))
+    }
+}
+
+#[cfg(feature = "dy_card")]
+impl VxwkBlockingAPI {
+    /// 抖音卡片接口
+    pub fn dy_cards(&self) -> BlockingDyCards<'_> {
+        BlockingDyCards { api: self }
+    }
+}
+
+/// 同步版本的抖音卡片接口，通过 [`VxwkBlockingAPI::dy_cards`] 获取
+#[cfg(feature = "dy_card")]
+#[derive(Clone, Copy)]
+pub struct BlockingDyCards<'a> {
+    api: &'a VxwkBlockingAPI,
+}
+
+#[cfg(feature = "dy_card")]
+impl<'a> BlockingDyCards<'a> {
+    /// 抖音卡片列表，按类型化的筛选条件查询
+    pub fn list(&self, filter: &DyCardListFilter) -> Result<ApiResponse<Page<DyCard>>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.dy_cards().list(filter))
+    }
+
+    /// 按筛选条件逐页拉取全部抖音卡片，迭代时按需请求
+    pub fn stream(
+        self,
+        filter: DyCardListFilter,
+        opt: PageOptions,
+    ) -> BlockingListIter<'a, DyCard> {
+        self.api.iter(self.api.inner.dy_cards().stream(filter, opt))
+    }
+
+    /// 抖音卡片详情
+    pub fn get(&self, id: &str) -> Result<ApiResponse<DyCard>, VxwkError> {
+        self.api.runtime.block_on(self.api.inner.dy_cards().get(id))
+    }
+
+    /// 创建抖音卡片，发送前校验字段
+    pub fn create(&self, req: &CreateDyCard) -> Result<ApiResponse<DyCard>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.dy_cards().create(req))
+    }
+
+    /// 更新抖音卡片，发送前校验字段，只发送 `req` 中设置过的字段
+    pub fn update(&self, id: &str, req: &UpdateDyCard) -> Result<ApiResponse<()>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.dy_cards().update(id, req))
+    }
+
+    /// 删除抖音卡片
+    pub fn delete(&self, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.dy_cards().delete(id))
+    }
+
+    /// 创建抖音卡片，请求体可以是任意可序列化的结构或 `serde_json::Value`
+    pub fn create_json<T>(&self, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .runtime
+            .block_on(self.api.inner.dy_cards().create_json(body))
+    }
+
+    /// 更新抖音卡片，`id` 会合并进请求体，其它字段保留原本的 JSON 类型
+    pub fn update_json<T>(&self, id: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .runtime
+            .block_on(self.api.inner.dy_cards().update_json(id, body))
+    }
+
+    /// 抖音卡片图片地址
+    pub fn img_url(&self, id: &str) -> Result<String, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.dy_cards().img_url(id))
+    }
+
+    /// 批量创建抖音卡片，按输入顺序返回每一项的结果
+    pub fn batch_create(
+        &self,
+        reqs: &[CreateDyCard],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<DyCard>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.dy_cards().batch_create(reqs, opt))
+    }
+
+    /// 批量更新抖音卡片，`items` 为 `(id, 更新请求)`
+    pub fn batch_update<S: AsRef<str>>(
+        &self,
+        items: &[(S, UpdateDyCard)],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.dy_cards().batch_update(items, opt))
+    }
+
+    /// 批量删除抖音卡片
+    pub fn batch_delete<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.dy_cards().batch_delete(ids, opt))
+    }
+
+    /// 批量获取抖音卡片详情
+    pub fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<DyCard>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.dy_cards().get_many(ids, opt))
+    }
+}
+
+#[cfg(feature = "wx_card")]
+#[allow(deprecated)]
+impl VxwkBlockingAPI {
+    /// 微信卡片图片地址
+    #[deprecated(note = "use `wx_cards().img_url()` instead")]
+    pub fn wx_card_img_url(
+        &self,
+        id: &str,
+        opt: Option<HashMap<&str, &str>>,
+    ) -> Result<String, VxwkError> {
+        self.runtime.block_on(self.inner.wx_card_img_url(id, opt))
+    }
+
+    /// 微信卡片详情
+    #[deprecated(note = "use `wx_cards().list()` instead")]
+    pub fn wx_card_list(
+        &self,
+        opt_map: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.wx_card_list(opt_map))
+    }
+
+    /// 创建微信卡片
+    #[deprecated(note = "use `wx_cards().create()` instead")]
+    pub fn wx_card_create(&self, opt: HashMap<&str, &str>) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.wx_card_create(opt))
+    }
+
+    /// 更新微信卡片
+    #[deprecated(note = "use `wx_cards().update()` instead")]
+    pub fn wx_card_update(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.wx_card_update(id, opt))
+    }
+
+    /// 删除微信卡片
+    #[deprecated(note = "use `wx_cards().delete()` instead")]
+    pub fn wx_card_delete(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.wx_card_delete(id, opt))
+    }
+
+    /// 获取微信卡片详情
+    #[deprecated(note = "use `wx_cards().get()` instead")]
+    pub fn wx_card_info(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.wx_card_info(id, opt))
+    }
+}
+
+#[cfg(feature = "wx_card")]
+impl VxwkBlockingAPI {
+    /// 微信卡片接口
+    pub fn wx_cards(&self) -> BlockingWxCards<'_> {
+        BlockingWxCards { api: self }
+    }
+}
+
+/// 同步版本的微信卡片接口，通过 [`VxwkBlockingAPI::wx_cards`] 获取
+#[cfg(feature = "wx_card")]
+#[derive(Clone, Copy)]
+pub struct BlockingWxCards<'a> {
+    api: &'a VxwkBlockingAPI,
+}
+
+#[cfg(feature = "wx_card")]
+impl<'a> BlockingWxCards<'a> {
+    /// 微信卡片列表，按类型化的筛选条件查询
+    pub fn list(&self, filter: &WxCardListFilter) -> Result<ApiResponse<Page<WxCard>>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.wx_cards().list(filter))
+    }
+
+    /// 按筛选条件逐页拉取全部微信卡片，迭代时按需请求
+    pub fn stream(
+        self,
+        filter: WxCardListFilter,
+        opt: PageOptions,
+    ) -> BlockingListIter<'a, WxCard> {
+        self.api.iter(self.api.inner.wx_cards().stream(filter, opt))
+    }
+
+    /// 微信卡片详情
+    pub fn get(&self, id: &str) -> Result<ApiResponse<WxCard>, VxwkError> {
+        self.api.runtime.block_on(self.api.inner.wx_cards().get(id))
+    }
+
+    /// 创建微信卡片，发送前校验字段
+    pub fn create(&self, req: &CreateWxCard) -> Result<ApiResponse<WxCard>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.wx_cards().create(req))
+    }
+
+    /// 更新微信卡片，发送前校验字段，只发送 `req` 中设置过的字段
+    pub fn update(&self, id: &str, req: &UpdateWxCard) -> Result<ApiResponse<()>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.wx_cards().update(id, req))
+    }
+
+    /// 删除微信卡片
+    pub fn delete(&self, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.wx_cards().delete(id))
+    }
+
+    /// 创建微信卡片，请求体可以是任意可序列化的结构或 `serde_json::Value`
+    pub fn create_json<T>(&self, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .runtime
+            .block_on(self.api.inner.wx_cards().create_json(body))
+    }
+
+    /// 更新微信卡片，`id` 会合并进请求体，其它字段保留原本的 JSON 类型
+    pub fn update_json<T>(&self, id: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .runtime
+            .block_on(self.api.inner.wx_cards().update_json(id, body))
+    }
+
+    /// 微信卡片图片地址
+    pub fn img_url(&self, id: &str) -> Result<String, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.wx_cards().img_url(id))
+    }
+
+    /// 批量创建微信卡片，按输入顺序返回每一项的结果
+    pub fn batch_create(
+        &self,
+        reqs: &[CreateWxCard],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<WxCard>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.wx_cards().batch_create(reqs, opt))
+    }
+
+    /// 批量更新微信卡片，`items` 为 `(id, 更新请求)`
+    pub fn batch_update<S: AsRef<str>>(
+        &self,
+        items: &[(S, UpdateWxCard)],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.wx_cards().batch_update(items, opt))
+    }
+
+    /// 批量删除微信卡片
+    pub fn batch_delete<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.wx_cards().batch_delete(ids, opt))
+    }
+
+    /// 批量获取微信卡片详情
+    pub fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<WxCard>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.wx_cards().get_many(ids, opt))
+    }
+}
+
+#[cfg(feature = "live_code")]
+#[allow(deprecated)]
+impl VxwkBlockingAPI {
+    /// 获取活码列表
+    #[deprecated(note = "use `live_codes().list()` instead")]
+    pub fn live_code_list(&self, opt: HashMap<&str, &str>) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.live_code_list(opt))
+    }
+
+    /// 创建活码
+    #[deprecated(note = "use `live_codes().create()` instead")]
+    pub fn live_code_create(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.live_code_create(opt))
+    }
+
+    /// 更新活码信息
+    #[deprecated(note = "use `live_codes().update()` instead")]
+    pub fn live_code_update(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.live_code_update(id, opt))
+    }
+
+    /// 删除活码
+    #[deprecated(note = "use `live_codes().delete()` instead")]
+    pub fn live_code_delete(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.live_code_delete(id, opt))
+    }
+
+    /// 查询活码信息
+    #[deprecated(note = "use `live_codes().get()` instead")]
+    pub fn live_code_info(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.live_code_info(id, opt))
+    }
+}
+
+#[cfg(feature = "live_code")]
+impl VxwkBlockingAPI {
+    /// 活码接口
+    pub fn live_codes(&self) -> BlockingLiveCodes<'_> {
+        BlockingLiveCodes { api: self }
+    }
+}
+
+/// 同步版本的活码接口，通过 [`VxwkBlockingAPI::live_codes`] 获取
+#[cfg(feature = "live_code")]
+#[derive(Clone, Copy)]
+pub struct BlockingLiveCodes<'a> {
+    api: &'a VxwkBlockingAPI,
+}
+
+#[cfg(feature = "live_code")]
+impl<'a> BlockingLiveCodes<'a> {
+    /// 活码列表，按类型化的筛选条件查询
+    pub fn list(
+        &self,
+        filter: &LiveCodeListFilter,
+    ) -> Result<ApiResponse<Page<LiveCode>>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_codes().list(filter))
+    }
+
+    /// 按筛选条件逐页拉取全部活码，迭代时按需请求
+    pub fn stream(
+        self,
+        filter: LiveCodeListFilter,
+        opt: PageOptions,
+    ) -> BlockingListIter<'a, LiveCode> {
+        self.api
+            .iter(self.api.inner.live_codes().stream(filter, opt))
+    }
+
+    /// 活码详情
+    pub fn get(&self, id: &str) -> Result<ApiResponse<LiveCode>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_codes().get(id))
+    }
+
+    /// 创建活码，发送前校验字段
+    pub fn create(&self, req: &CreateLiveCode) -> Result<ApiResponse<LiveCode>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_codes().create(req))
+    }
+
+    /// 更新活码，发送前校验字段，只发送 `req` 中设置过的字段
+    pub fn update(&self, id: &str, req: &UpdateLiveCode) -> Result<ApiResponse<()>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_codes().update(id, req))
+    }
+
+    /// 删除活码
+    pub fn delete(&self, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_codes().delete(id))
+    }
+
+    /// 创建活码，请求体可以是任意可序列化的结构或 `serde_json::Value`
+    pub fn create_json<T>(&self, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_codes().create_json(body))
+    }
+
+    /// 更新活码，`id` 会合并进请求体，其它字段保留原本的 JSON 类型
+    pub fn update_json<T>(&self, id: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_codes().update_json(id, body))
+    }
+
+    /// 批量创建活码，按输入顺序返回每一项的结果
+    pub fn batch_create(
+        &self,
+        reqs: &[CreateLiveCode],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<LiveCode>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_codes().batch_create(reqs, opt))
+    }
+
+    /// 批量更新活码，`items` 为 `(id, 更新请求)`
+    pub fn batch_update<S: AsRef<str>>(
+        &self,
+        items: &[(S, UpdateLiveCode)],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_codes().batch_update(items, opt))
+    }
+
+    /// 批量删除活码
+    pub fn batch_delete<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_codes().batch_delete(ids, opt))
+    }
+
+    /// 批量获取活码详情
+    pub fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<LiveCode>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_codes().get_many(ids, opt))
+    }
+}
+
+#[cfg(feature = "live_code_file")]
+#[allow(deprecated)]
+impl VxwkBlockingAPI {
+    /// 获取活码文件列表
+    #[deprecated(note = "use `live_code_files().list()` instead")]
+    pub fn live_code_file_url_list(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime
+            .block_on(self.inner.live_code_file_url_list(opt))
+    }
+
+    /// 获取活码文件url
+    #[deprecated(note = "use `live_code_files().get()` instead")]
+    pub fn live_code_file_url(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime
+            .block_on(self.inner.live_code_file_url(id, opt))
+    }
+
+    /// 上传文件
+    #[deprecated(note = "use `live_code_files().create()` instead")]
+    pub fn live_code_file_upload(
+        &self,
+        file: Vec<u8>,
+        name: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime
+            .block_on(self.inner.live_code_file_upload(file, name, opt))
+    }
+
+    /// 修改活码名称
+    #[deprecated(note = "use `live_code_files().update()` instead")]
+    pub fn live_code_file_name_update(
+        &self,
+        id: &str,
+        name: &str,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime
+            .block_on(self.inner.live_code_file_name_update(id, name))
+    }
+
+    /// 删除活码文件
+    #[deprecated(note = "use `live_code_files().delete()` instead")]
+    pub fn live_code_file_delete(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime
+            .block_on(self.inner.live_code_file_delete(id, opt))
+    }
+}
+
+#[cfg(feature = "live_code_file")]
+impl VxwkBlockingAPI {
+    /// 活码文件接口
+    pub fn live_code_files(&self) -> BlockingLiveCodeFiles<'_> {
+        BlockingLiveCodeFiles { api: self }
+    }
+}
+
+/// 同步版本的活码文件接口，通过 [`VxwkBlockingAPI::live_code_files`] 获取
+#[cfg(feature = "live_code_file")]
+#[derive(Clone, Copy)]
+pub struct BlockingLiveCodeFiles<'a> {
+    api: &'a VxwkBlockingAPI,
+}
+
+#[cfg(feature = "live_code_file")]
+impl<'a> BlockingLiveCodeFiles<'a> {
+    /// 活码文件列表，按类型化的筛选条件查询
+    pub fn list(
+        &self,
+        filter: &LiveCodeFileListFilter,
+    ) -> Result<ApiResponse<Page<LiveCodeFile>>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_code_files().list(filter))
+    }
+
+    /// 按筛选条件逐页拉取全部活码文件，迭代时按需请求
+    pub fn stream(
+        self,
+        filter: LiveCodeFileListFilter,
+        opt: PageOptions,
+    ) -> BlockingListIter<'a, LiveCodeFile> {
+        self.api
+            .iter(self.api.inner.live_code_files().stream(filter, opt))
+    }
+
+    /// 获取某个活码关联的文件
+    pub fn by_live_code(
+        &self,
+        live_code_id: &str,
+    ) -> Result<ApiResponse<Page<LiveCodeFile>>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_code_files().by_live_code(live_code_id))
+    }
+
+    /// 活码文件详情，包含文件的访问地址
+    pub fn get(&self, id: &str) -> Result<ApiResponse<LiveCodeFile>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_code_files().get(id))
+    }
+
+    /// 上传文件，返回新文件的 id
+    pub fn create(
+        &self,
+        file: Vec<u8>,
+        name: &str,
+    ) -> Result<ApiResponse<UploadedFile>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_code_files().create(file, name))
+    }
+
+    /// 上传文件，见 [`LiveCodeFiles::create_with`](crate::LiveCodeFiles::create_with)
+    pub fn create_with(
+        &self,
+        file: Vec<u8>,
+        name: &str,
+        opt: UploadOptions,
+    ) -> Result<ApiResponse<UploadedFile>, VxwkError> {
+        self.api.runtime.block_on(
+            self.api
+                .inner
+                .live_code_files()
+                .create_with(file, name, opt),
+        )
+    }
+
+    /// 从本地路径流式上传文件，见 [`LiveCodeFiles::upload_path`](crate::LiveCodeFiles::upload_path)
+    #[cfg(feature = "stream_upload")]
+    pub fn upload_path(
+        &self,
+        path: impl AsRef<Path>,
+        name: &str,
+        opt: UploadOptions,
+    ) -> Result<ApiResponse<UploadedFile>, VxwkError> {
+        self.api.runtime.block_on(
+            self.api
+                .inner
+                .live_code_files()
+                .upload_path(path, name, opt),
+        )
+    }
+
+    /// 修改文件名称
+    pub fn update(&self, id: &str, name: &str) -> Result<ApiResponse<()>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_code_files().update(id, name))
+    }
+
+    /// 删除活码文件
+    pub fn delete(&self, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_code_files().delete(id))
+    }
+
+    /// 批量创建活码文件，按输入顺序返回每一项的结果
+    pub fn batch_create(
+        &self,
+        reqs: &[CreateLiveCodeFile],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<UploadedFile>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_code_files().batch_create(reqs, opt))
+    }
+
+    /// 批量更新活码文件，`items` 为 `(id, 更新请求)`
+    pub fn batch_update<S: AsRef<str>>(
+        &self,
+        items: &[(S, UpdateLiveCodeFile)],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_code_files().batch_update(items, opt))
+    }
+
+    /// 批量删除活码文件
+    pub fn batch_delete<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_code_files().batch_delete(ids, opt))
+    }
+
+    /// 批量获取活码文件详情
+    pub fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<LiveCodeFile>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.live_code_files().get_many(ids, opt))
+    }
+}
+
+#[cfg(feature = "external")]
+#[allow(deprecated)]
+impl VxwkBlockingAPI {
+    /// 查询外联显示logo
+    #[deprecated(note = "use `external_links().logo_url()` instead")]
+    pub fn external_logo_url(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<String, VxwkError> {
+        self.runtime.block_on(self.inner.external_logo_url(id, opt))
+    }
+
+    /// 外链列表
+    #[deprecated(note = "use `external_links().list()` instead")]
+    pub fn external_url_list(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.external_url_list(opt))
+    }
+
+    /// 获取外链详情
+    #[deprecated(note = "use `external_links().get()` instead")]
+    pub fn external_url_info(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.external_url_info(id, opt))
+    }
+
+    /// 添加外链
+    #[deprecated(note = "use `external_links().create()` instead")]
+    pub fn external_url_create(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.external_url_create(opt))
+    }
+
+    /// 修改外联
+    #[deprecated(note = "use `external_links().update()` instead")]
+    pub fn external_url_update(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.external_url_update(opt))
+    }
+
+    /// 外链删除
+    #[deprecated(note = "use `external_links().delete()` instead")]
+    pub fn external_url_logo_delete(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime
+            .block_on(self.inner.external_url_logo_delete(id, opt))
+    }
+}
+
+#[cfg(feature = "external")]
+impl VxwkBlockingAPI {
+    /// 外链接口
+    pub fn external_links(&self) -> BlockingExternalLinks<'_> {
+        BlockingExternalLinks { api: self }
+    }
+}
+
+/// 同步版本的外链接口，通过 [`VxwkBlockingAPI::external_links`] 获取
+#[cfg(feature = "external")]
+#[derive(Clone, Copy)]
+pub struct BlockingExternalLinks<'a> {
+    api: &'a VxwkBlockingAPI,
+}
+
+#[cfg(feature = "external")]
+impl<'a> BlockingExternalLinks<'a> {
+    /// 外链列表，按类型化的筛选条件查询
+    pub fn list(
+        &self,
+        filter: &ExternalLinkListFilter,
+    ) -> Result<ApiResponse<Page<ExternalLink>>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.external_links().list(filter))
+    }
+
+    /// 按筛选条件逐页拉取全部外链，迭代时按需请求
+    pub fn stream(
+        self,
+        filter: ExternalLinkListFilter,
+        opt: PageOptions,
+    ) -> BlockingListIter<'a, ExternalLink> {
+        self.api
+            .iter(self.api.inner.external_links().stream(filter, opt))
+    }
+
+    /// 外链详情
+    pub fn get(&self, id: &str) -> Result<ApiResponse<ExternalLink>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.external_links().get(id))
+    }
+
+    /// 创建外链，发送前校验字段
+    pub fn create(&self, req: &CreateExternalLink) -> Result<ApiResponse<ExternalLink>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.external_links().create(req))
+    }
+
+    /// 更新外链，发送前校验字段，只发送 `req` 中设置过的字段
+    pub fn update(&self, id: &str, req: &UpdateExternalLink) -> Result<ApiResponse<()>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.external_links().update(id, req))
+    }
+
+    /// 删除外链
+    pub fn delete(&self, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.external_links().delete(id))
+    }
+
+    /// 创建外链，请求体可以是任意可序列化的结构或 `serde_json::Value`
+    pub fn create_json<T>(&self, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .runtime
+            .block_on(self.api.inner.external_links().create_json(body))
+    }
+
+    /// 更新外链，`id` 会合并进请求体，其它字段保留原本的 JSON 类型
+    pub fn update_json<T>(&self, id: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .runtime
+            .block_on(self.api.inner.external_links().update_json(id, body))
+    }
+
+    /// 外链显示的 logo 地址
+    pub fn logo_url(&self, id: &str) -> Result<String, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.external_links().logo_url(id))
+    }
+
+    /// 批量创建外链，按输入顺序返回每一项的结果
+    pub fn batch_create(
+        &self,
+        reqs: &[CreateExternalLink],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<ExternalLink>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.external_links().batch_create(reqs, opt))
+    }
+
+    /// 批量更新外链，`items` 为 `(id, 更新请求)`
+    pub fn batch_update<S: AsRef<str>>(
+        &self,
+        items: &[(S, UpdateExternalLink)],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.external_links().batch_update(items, opt))
+    }
+
+    /// 批量删除外链
+    pub fn batch_delete<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.external_links().batch_delete(ids, opt))
+    }
+
+    /// 批量获取外链详情
+    pub fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<ExternalLink>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.external_links().get_many(ids, opt))
+    }
+
+    /// 导出外链为 CSV/TSV，返回写入的行数
+    #[cfg(feature = "csv")]
+    pub fn export_csv<W: Write>(
+        &self,
+        filter: ExternalLinkListFilter,
+        writer: W,
+        opt: &SheetOptions,
+    ) -> Result<usize, VxwkError> {
+        self.api.runtime.block_on(
+            self.api
+                .inner
+                .external_links()
+                .export_csv(filter, writer, opt),
+        )
+    }
+
+    /// 从 CSV/TSV 导入外链，结果写入 `result`
+    #[cfg(feature = "csv")]
+    pub fn import_csv<I: Read, W: Write>(
+        &self,
+        input: I,
+        result: W,
+        opt: &SheetOptions,
+        batch_opt: BatchOptions,
+    ) -> Result<ImportReport, VxwkError> {
+        self.api.runtime.block_on(
+            self.api
+                .inner
+                .external_links()
+                .import_csv(input, result, opt, batch_opt),
+        )
+    }
+}
+
+#[cfg(feature = "short_link")]
+#[allow(deprecated)]
+impl VxwkBlockingAPI {
+    /// 短链列表
+    #[deprecated(note = "use `short_links().list()` instead")]
+    pub fn short_link_list(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.short_link_list(opt))
+    }
+
+    /// 获取短连接详情
+    #[deprecated(note = "use `short_links().get()` instead")]
+    pub fn short_link_detail(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.short_link_detail(id, opt))
+    }
+
+    /// 创建新的短链
+    #[deprecated(note = "use `short_links().create()` instead")]
+    pub fn short_link_create(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.short_link_create(opt))
+    }
+
+    /// 更新短链
+    #[deprecated(note = "use `short_links().update()` instead")]
+    pub fn short_link_update(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.short_link_update(id, opt))
+    }
+
+    /// 删除短链
+    #[deprecated(note = "use `short_links().delete()` instead")]
+    pub fn short_link_delete(&self, id: &str) -> Result<serde_json::Value, VxwkError> {
+        self.runtime.block_on(self.inner.short_link_delete(id))
+    }
+}
+
+#[cfg(feature = "short_link")]
+impl VxwkBlockingAPI {
+    /// 短链接口
+    pub fn short_links(&self) -> BlockingShortLinks<'_> {
+        BlockingShortLinks { api: self }
+    }
+}
+
+/// 同步版本的短链接口，通过 [`VxwkBlockingAPI::short_links`] 获取
+#[cfg(feature = "short_link")]
+#[derive(Clone, Copy)]
+pub struct BlockingShortLinks<'a> {
+    api: &'a VxwkBlockingAPI,
+}
+
+#[cfg(feature = "short_link")]
+impl<'a> BlockingShortLinks<'a> {
+    /// 短链列表，按类型化的筛选条件查询
+    pub fn list(
+        &self,
+        filter: &ShortLinkListFilter,
+    ) -> Result<ApiResponse<Page<ShortLink>>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().list(filter))
+    }
+
+    /// 按筛选条件逐页拉取全部短链，迭代时按需请求
+    pub fn stream(
+        self,
+        filter: ShortLinkListFilter,
+        opt: PageOptions,
+    ) -> BlockingListIter<'a, ShortLink> {
+        self.api
+            .iter(self.api.inner.short_links().stream(filter, opt))
+    }
+
+    /// 短链详情
+    pub fn get(&self, id: &str) -> Result<ApiResponse<ShortLink>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().get(id))
+    }
+
+    /// 创建短链，发送前校验字段
+    pub fn create(&self, req: &CreateShortLink) -> Result<ApiResponse<ShortLink>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().create(req))
+    }
+
+    /// 更新短链，发送前校验字段，只发送 `req` 中设置过的字段
+    pub fn update(&self, id: &str, req: &UpdateShortLink) -> Result<ApiResponse<()>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().update(id, req))
+    }
+
+    /// 删除短链
+    pub fn delete(&self, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().delete(id))
+    }
+
+    /// 幂等创建，见 [`ShortLinks::find_or_create`](crate::ShortLinks::find_or_create)
+    pub fn find_or_create(
+        &self,
+        req: &CreateShortLink,
+        idempotency_key: Option<&str>,
+    ) -> Result<FindOrCreate<ShortLink>, VxwkError> {
+        self.api.runtime.block_on(
+            self.api
+                .inner
+                .short_links()
+                .find_or_create(req, idempotency_key),
+        )
+    }
+
+    /// 按自定义规则匹配已有短链的幂等创建
+    pub fn find_or_create_by<F>(
+        &self,
+        req: &CreateShortLink,
+        filter: ShortLinkListFilter,
+        idempotency_key: Option<&str>,
+        matches: F,
+    ) -> Result<FindOrCreate<ShortLink>, VxwkError>
+    where
+        F: Fn(&ShortLink) -> bool,
+    {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().find_or_create_by(
+                req,
+                filter,
+                idempotency_key,
+                matches,
+            ))
+    }
+
+    /// 创建短链，请求体可以是任意可序列化的结构或 `serde_json::Value`
+    pub fn create_json<T>(&self, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().create_json(body))
+    }
+
+    /// 更新短链，`id` 会合并进请求体，其它字段保留原本的 JSON 类型
+    pub fn update_json<T>(&self, id: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().update_json(id, body))
+    }
+
+    /// 批量创建短链，按输入顺序返回每一项的结果
+    pub fn batch_create(
+        &self,
+        reqs: &[CreateShortLink],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<ShortLink>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().batch_create(reqs, opt))
+    }
+
+    /// 批量更新短链，`items` 为 `(id, 更新请求)`
+    pub fn batch_update<S: AsRef<str>>(
+        &self,
+        items: &[(S, UpdateShortLink)],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().batch_update(items, opt))
+    }
+
+    /// 批量删除短链
+    pub fn batch_delete<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().batch_delete(ids, opt))
+    }
+
+    /// 批量获取短链详情
+    pub fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<ShortLink>, VxwkError>> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().get_many(ids, opt))
+    }
+
+    /// 导出短链为 CSV/TSV，返回写入的行数
+    #[cfg(feature = "csv")]
+    pub fn export_csv<W: Write>(
+        &self,
+        filter: ShortLinkListFilter,
+        writer: W,
+        opt: &SheetOptions,
+    ) -> Result<usize, VxwkError> {
+        self.api
+            .runtime
+            .block_on(self.api.inner.short_links().export_csv(filter, writer, opt))
+    }
+
+    /// 从 CSV/TSV 导入短链，结果写入 `result`
+    #[cfg(feature = "csv")]
+    pub fn import_csv<I: Read, W: Write>(
+        &self,
+        input: I,
+        result: W,
+        opt: &SheetOptions,
+        batch_opt: BatchOptions,
+    ) -> Result<ImportReport, VxwkError> {
+        self.api.runtime.block_on(
+            self.api
+                .inner
+                .short_links()
+                .import_csv(input, result, opt, batch_opt),
+        )
+    }
+}
+
+#[cfg(feature = "reconcile")]
+impl VxwkBlockingAPI {
+    /// 生成同步计划，见 [`VxwkAPI::plan`]
+    pub fn plan(&self, desired: &DesiredState, opt: PlanOptions) -> Result<Plan, VxwkError> {
+        self.runtime.block_on(self.inner.plan(desired, opt))
+    }
+
+    /// 执行同步计划，见 [`VxwkAPI::apply`]
+    pub fn apply<F>(&self, plan: &Plan, opt: BatchOptions, progress: F) -> ApplyReport
+    where
+        F: Fn(&Progress<'_>) + Sync,
+    {
+        self.runtime.block_on(self.inner.apply(plan, opt, progress))
+    }
+}
+
+#[cfg(feature = "backup")]
+impl VxwkBlockingAPI {
+    /// 备份全部资源到目录，见 [`VxwkAPI::backup`]
+    pub fn backup(
+        &self,
+        dir: impl AsRef<Path>,
+        opt: BatchOptions,
+    ) -> Result<BackupManifest, VxwkError> {
+        self.runtime.block_on(self.inner.backup(dir, opt))
+    }
+
+    /// 从目录中的备份恢复，见 [`VxwkAPI::restore`]
+    pub fn restore(
+        &self,
+        dir: impl AsRef<Path>,
+        opt: BatchOptions,
+    ) -> Result<RestoreReport, VxwkError> {
+        self.runtime.block_on(self.inner.restore(dir, opt))
+    }
+}
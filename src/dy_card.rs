@@ -0,0 +1,209 @@
+//! 抖音卡片相关api
+use crate::batch::BatchOptions;
+use crate::model::dycard::{CreateDyCard, DyCard, DyCardListFilter, UpdateDyCard};
+use crate::model::{with_id, ApiResponse, Page};
+use crate::paginate::{ListStream, PageOptions};
+use crate::resource::{self, DyCardResource, Resource};
+use crate::{VxwkAPI, VxwkError};
+use serde::Serialize;
+use std::collections::HashMap;
+
+impl VxwkAPI {
+    /// 抖音卡片图片地址
+    #[deprecated(note = "use `dy_cards().img_url()` instead")]
+    pub async fn dy_card_img_url(
+        &self,
+        id: &str,
+        opt: Option<HashMap<&str, &str>>,
+    ) -> Result<String, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("projectid", id);
+        if let Some(opt_map) = opt {
+            // 如果有传入的Option参数，将其合并到query_params中
+            query_params.extend(opt_map);
+        }
+        let result = self.get("/api/v1/user/carddy/img", query_params).await?;
+        let file_url = result.headers().get("Location");
+        if let Some(file_url) = file_url {
+            return Ok(file_url.to_str().unwrap().to_string());
+        }
+        Err(VxwkError::InvalidRequest("get url fail".into()))
+    }
+    /// 抖音卡片列表
+    #[deprecated(note = "use `dy_cards().list()` instead")]
+    pub async fn dy_card_get_list(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let result = self.get("/api/v1/user/carddy/list", opt).await?;
+        Ok(result.json().await?)
+    }
+    /// 抖音卡片详情
+    #[deprecated(note = "use `dy_cards().get()` instead")]
+    pub async fn dy_card_get_info(
+        &self,
+        id: &str,
+        opt: Option<HashMap<&str, &str>>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        if let Some(opt_map) = opt {
+            // 如果有传入的Option参数，将其合并到query_params中
+            query_params.extend(opt_map);
+        }
+        let result = self.get("/api/v1/user/carddy", query_params).await?;
+        Ok(result.json().await?)
+    }
+    /// 抖音卡片创建
+    #[deprecated(note = "use `dy_cards().create()` instead")]
+    pub async fn dy_card_create(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let result = self.post("/api/v1/user/carddy", &opt).await?;
+        Ok(result)
+    }
+
+    /// 抖音卡片修改
+    #[deprecated(note = "use `dy_cards().update()` instead")]
+    pub async fn dy_card_update(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self
+            .post("/api/v1/user/carddy/update", &query_params)
+            .await?;
+        Ok(result)
+    }
+    /// 抖音卡片删除
+    #[deprecated(note = "use `dy_cards().delete()` instead")]
+    pub async fn dy_card_delete(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self
+            .post("/api/v1/user/carddy/delete", &query_params)
+            .await?;
+        Ok(result)
+    }
+
+    /// 抖音卡片接口
+    pub fn dy_cards(&self) -> DyCards<'_> {
+        DyCards { api: self }
+    }
+}
+
+/// 抖音卡片接口，通过 [`VxwkAPI::dy_cards`] 获取
+#[derive(Clone, Copy)]
+pub struct DyCards<'a> {
+    api: &'a VxwkAPI,
+}
+
+impl<'a> DyCards<'a> {
+    /// 抖音卡片列表，按类型化的筛选条件查询
+    pub async fn list(
+        &self,
+        filter: &DyCardListFilter,
+    ) -> Result<ApiResponse<Page<DyCard>>, VxwkError> {
+        resource::list::<DyCardResource>(self.api, filter).await
+    }
+
+    /// 按筛选条件逐页拉取全部抖音卡片，返回按需请求的流
+    ///
+    /// `filter` 中的分页字段会被 `opt` 覆盖
+    pub fn stream(self, filter: DyCardListFilter, opt: PageOptions) -> ListStream<'a, DyCard> {
+        resource::stream::<DyCardResource>(self.api, filter, opt)
+    }
+
+    /// 抖音卡片详情
+    pub async fn get(&self, id: &str) -> Result<ApiResponse<DyCard>, VxwkError> {
+        resource::get::<DyCardResource>(self.api, id).await
+    }
+
+    /// 抖音卡片创建，发送前校验字段
+    pub async fn create(&self, req: &CreateDyCard) -> Result<ApiResponse<DyCard>, VxwkError> {
+        resource::create::<DyCardResource>(self.api, req).await
+    }
+
+    /// 抖音卡片修改，发送前校验字段，只发送 `req` 中设置过的字段
+    pub async fn update(&self, id: &str, req: &UpdateDyCard) -> Result<ApiResponse<()>, VxwkError> {
+        resource::update::<DyCardResource>(self.api, id, req).await
+    }
+
+    /// 抖音卡片删除
+    pub async fn delete(&self, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+        resource::delete::<DyCardResource>(self.api, id).await
+    }
+
+    /// 创建抖音卡片，请求体可以是任意可序列化的结构或 `serde_json::Value`
+    pub async fn create_json<T>(&self, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api.post(DyCardResource::CREATE_PATH, body).await
+    }
+
+    /// 更新抖音卡片，`id` 会合并进请求体，其它字段保留原本的 JSON 类型
+    pub async fn update_json<T>(&self, id: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .post(DyCardResource::UPDATE_PATH, &with_id(id, body)?)
+            .await
+    }
+
+    /// 抖音卡片图片地址
+    pub async fn img_url(&self, id: &str) -> Result<String, VxwkError> {
+        let query_params = HashMap::from([("projectid", id)]);
+        let result = self.api.get("/api/v1/user/carddy/img", query_params).await?;
+        match result.headers().get("Location") {
+            Some(file_url) => Ok(file_url.to_str()?.to_string()),
+            None => Err(VxwkError::InvalidRequest("get url fail".into())),
+        }
+    }
+
+    /// 批量创建抖音卡片，按输入顺序返回每一项的结果
+    pub async fn batch_create(
+        &self,
+        reqs: &[CreateDyCard],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<DyCard>, VxwkError>> {
+        resource::batch_create::<DyCardResource>(self.api, reqs, opt).await
+    }
+
+    /// 批量更新抖音卡片，`items` 为 `(id, 更新请求)`
+    pub async fn batch_update<S: AsRef<str>>(
+        &self,
+        items: &[(S, UpdateDyCard)],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        resource::batch_update::<DyCardResource, S>(self.api, items, opt).await
+    }
+
+    /// 批量删除抖音卡片
+    pub async fn batch_delete<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        resource::batch_delete::<DyCardResource, S>(self.api, ids, opt).await
+    }
+
+    /// 批量获取抖音卡片详情
+    pub async fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<DyCard>, VxwkError>> {
+        resource::get_many::<DyCardResource, S>(self.api, ids, opt).await
+    }
+}
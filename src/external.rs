@@ -0,0 +1,271 @@
+//! 外链相关api
+use crate::batch::BatchOptions;
+use crate::model::external::{
+    CreateExternalLink, ExternalLink, ExternalLinkListFilter, UpdateExternalLink,
+};
+use crate::model::{with_id, ApiResponse, Page};
+use crate::paginate::{ListStream, PageOptions};
+use crate::resource::{self, ExternalLinkResource, Resource};
+#[cfg(feature = "csv")]
+use crate::sheet::{self, ImportReport, SheetOptions};
+use crate::{VxwkAPI, VxwkError};
+use serde::Serialize;
+use std::collections::HashMap;
+#[cfg(feature = "csv")]
+use std::io::{Read, Write};
+
+impl VxwkAPI {
+    /// 查询外联显示logo
+    #[deprecated(note = "use `external_links().logo_url()` instead")]
+    pub async fn external_logo_url(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<String, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("projectid", id);
+        query_params.extend(opt);
+        let result = self.get("/api/v1/admin/external/img", query_params).await?;
+        let file_url = &result.headers().get("Location");
+        if let Some(file_url) = file_url {
+            return Ok(file_url.to_str()?.to_string());
+        }
+        Err(VxwkError::InvalidRequest("get url fail".into()))
+    }
+
+    /// 外链列表
+    #[deprecated(note = "use `external_links().list()` instead")]
+    pub async fn external_url_list(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let result = self.get("/api/v1/user/external/list", opt).await?;
+        Ok(result.json().await?)
+    }
+
+    /// 获取外链详情
+    #[deprecated(note = "use `external_links().get()` instead")]
+    pub async fn external_url_info(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self.get("/api/v1/user/external", query_params).await?;
+        Ok(result.json().await?)
+    }
+
+    /// 添加外链
+    /// ```json
+    /// {
+    ///   "domainID": "some-uuid-string",
+    ///   "title": "ShortLinkTitle",
+    ///   "describe": "ShortLinkDescription",
+    ///   "tips": "OptionalTips",
+    ///   "img": null,
+    ///   "type": 1,
+    ///   "startAt": 0,
+    ///   "stopAt": 0,
+    ///   "link": [],
+    ///   "hash": "HashValue",
+    ///   "testMode": true,
+    ///   "style": "SomeStyle"
+    /// }
+    /// ```
+    // 请注意：
+    // - "domainID" 是一个 UUID 字符串，所以需要按照 UUID 的格式提供。
+    // - "title" 最大长度为 20。
+    // - "describe" 最大长度为 100。
+    // - "tips" 最大长度为 100，是可选的。
+    // - "img" 是可选的，这里设置为 `null`。
+    // - "type" 是一个整数。
+    // - "startAt" 和 "stopAt" 是可选的，设置为 0。
+    // - "link" 是一个数组，这里为空数组。
+    // - "hash" 最大长度为 100，是可选的。
+    // - "testMode" 是一个布尔值。
+    // - "style" 需要符合 "Style" 的格式。
+    #[deprecated(note = "use `external_links().create()` instead")]
+    pub async fn external_url_create(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let result = self.post("/api/v1/user/external/create", &opt).await?;
+        Ok(result)
+    }
+
+    /// 修改外联
+    #[deprecated(note = "use `external_links().update()` instead")]
+    pub async fn external_url_update(&self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let result = self.post("/api/v1/user/external/update", &opt).await?;
+        Ok(result)
+    }
+
+    ///外链删除
+    #[deprecated(note = "use `external_links().delete()` instead")]
+    pub async fn external_url_logo_delete(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self
+            .post("/api/v1/user/external/delete", &query_params)
+            .await?;
+        Ok(result)
+    }
+
+    /// 外链接口
+    pub fn external_links(&self) -> ExternalLinks<'_> {
+        ExternalLinks { api: self }
+    }
+}
+
+/// 外链接口，通过 [`VxwkAPI::external_links`] 获取
+#[derive(Clone, Copy)]
+pub struct ExternalLinks<'a> {
+    api: &'a VxwkAPI,
+}
+
+impl<'a> ExternalLinks<'a> {
+    /// 外链列表，按类型化的筛选条件查询
+    pub async fn list(
+        &self,
+        filter: &ExternalLinkListFilter,
+    ) -> Result<ApiResponse<Page<ExternalLink>>, VxwkError> {
+        resource::list::<ExternalLinkResource>(self.api, filter).await
+    }
+
+    /// 按筛选条件逐页拉取全部外链，返回按需请求的流
+    ///
+    /// `filter` 中的分页字段会被 `opt` 覆盖
+    pub fn stream(
+        self,
+        filter: ExternalLinkListFilter,
+        opt: PageOptions,
+    ) -> ListStream<'a, ExternalLink> {
+        resource::stream::<ExternalLinkResource>(self.api, filter, opt)
+    }
+
+    /// 外链详情
+    pub async fn get(&self, id: &str) -> Result<ApiResponse<ExternalLink>, VxwkError> {
+        resource::get::<ExternalLinkResource>(self.api, id).await
+    }
+
+    /// 添加外链，发送前按文档中的约束校验字段
+    pub async fn create(
+        &self,
+        req: &CreateExternalLink,
+    ) -> Result<ApiResponse<ExternalLink>, VxwkError> {
+        resource::create::<ExternalLinkResource>(self.api, req).await
+    }
+
+    /// 修改外链，发送前校验字段，只发送 `req` 中设置过的字段
+    pub async fn update(
+        &self,
+        id: &str,
+        req: &UpdateExternalLink,
+    ) -> Result<ApiResponse<()>, VxwkError> {
+        resource::update::<ExternalLinkResource>(self.api, id, req).await
+    }
+
+    /// 删除外链
+    pub async fn delete(&self, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+        resource::delete::<ExternalLinkResource>(self.api, id).await
+    }
+
+    /// 创建外链，请求体可以是任意可序列化的结构或 `serde_json::Value`
+    pub async fn create_json<T>(&self, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api.post(ExternalLinkResource::CREATE_PATH, body).await
+    }
+
+    /// 更新外链，`id` 会合并进请求体，其它字段保留原本的 JSON 类型
+    pub async fn update_json<T>(&self, id: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .post(ExternalLinkResource::UPDATE_PATH, &with_id(id, body)?)
+            .await
+    }
+
+    /// 外链显示的 logo 地址
+    pub async fn logo_url(&self, id: &str) -> Result<String, VxwkError> {
+        let query_params = HashMap::from([("projectid", id)]);
+        let result = self
+            .api
+            .get("/api/v1/admin/external/img", query_params)
+            .await?;
+        match result.headers().get("Location") {
+            Some(file_url) => Ok(file_url.to_str()?.to_string()),
+            None => Err(VxwkError::InvalidRequest("get url fail".into())),
+        }
+    }
+
+    /// 批量创建外链，按输入顺序返回每一项的结果
+    pub async fn batch_create(
+        &self,
+        reqs: &[CreateExternalLink],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<ExternalLink>, VxwkError>> {
+        resource::batch_create::<ExternalLinkResource>(self.api, reqs, opt).await
+    }
+
+    /// 批量更新外链，`items` 为 `(id, 更新请求)`
+    pub async fn batch_update<S: AsRef<str>>(
+        &self,
+        items: &[(S, UpdateExternalLink)],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        resource::batch_update::<ExternalLinkResource, S>(self.api, items, opt).await
+    }
+
+    /// 批量删除外链
+    pub async fn batch_delete<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        resource::batch_delete::<ExternalLinkResource, S>(self.api, ids, opt).await
+    }
+
+    /// 批量获取外链详情
+    pub async fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<ExternalLink>, VxwkError>> {
+        resource::get_many::<ExternalLinkResource, S>(self.api, ids, opt).await
+    }
+
+    /// 导出外链为 CSV/TSV，返回写入的行数，见 [`sheet`](crate::sheet)
+    #[cfg(feature = "csv")]
+    pub async fn export_csv<W: Write>(
+        &self,
+        filter: ExternalLinkListFilter,
+        writer: W,
+        opt: &SheetOptions,
+    ) -> Result<usize, VxwkError> {
+        sheet::export::<ExternalLinkResource, W>(self.api, filter, writer, opt).await
+    }
+
+    /// 从 CSV/TSV 导入外链，`id` 列有值的行更新，其它行创建，结果写入 `result`
+    #[cfg(feature = "csv")]
+    pub async fn import_csv<I: Read, W: Write>(
+        &self,
+        input: I,
+        result: W,
+        opt: &SheetOptions,
+        batch_opt: BatchOptions,
+    ) -> Result<ImportReport, VxwkError> {
+        sheet::import::<ExternalLinkResource, I, W>(self.api, input, result, opt, batch_opt).await
+    }
+}
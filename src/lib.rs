@@ -1,57 +1,127 @@
-use base64::Engine;
-use base64::{alphabet, engine, read, write};
-use model::livecodefile::{UpdateFileReq, UpdateLiveCodeNameReq};
+use node::{Node, NodePool};
 use reqwest::header::ToStrError;
-use reqwest::Response;
+use reqwest::{Method, Response, StatusCode};
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use urand::generate_unique_number;
 use url::ParseError;
 use url::Url;
 use url::form_urlencoded;
-///  这里是Vxwk项目对外开放的所有Api 
-/// 简单使用案列
-/// ```
-///     use std::collections::HashMap;
 
-/// use tokio::runtime;
-/// use crate::VxwkConfig;
+#[cfg(feature = "backup")]
+pub mod backup;
+mod batch;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "dy_card")]
+mod dy_card;
+#[cfg(feature = "external")]
+mod external;
+#[cfg(feature = "live_code")]
+mod live_code;
+#[cfg(feature = "live_code_file")]
+mod live_code_file;
+#[cfg(feature = "short_link")]
+mod short_link;
+#[cfg(feature = "wx_card")]
+mod wx_card;
+pub mod model;
+mod paginate;
+#[cfg(feature = "reconcile")]
+pub mod reconcile;
+pub mod resource;
+#[cfg(feature = "csv")]
+pub mod sheet;
+#[cfg(feature = "stream_upload")]
+mod upload;
+mod validate;
+#[cfg(feature = "blocking")]
+pub use blocking::{BlockingListIter, VxwkBlockingAPI};
+#[cfg(all(feature = "blocking", feature = "dy_card"))]
+pub use blocking::BlockingDyCards;
+#[cfg(all(feature = "blocking", feature = "external"))]
+pub use blocking::BlockingExternalLinks;
+#[cfg(all(feature = "blocking", feature = "live_code"))]
+pub use blocking::BlockingLiveCodes;
+#[cfg(all(feature = "blocking", feature = "live_code_file"))]
+pub use blocking::BlockingLiveCodeFiles;
+#[cfg(all(feature = "blocking", feature = "short_link"))]
+pub use blocking::BlockingShortLinks;
+#[cfg(all(feature = "blocking", feature = "wx_card"))]
+pub use blocking::BlockingWxCards;
+#[cfg(feature = "dy_card")]
+pub use dy_card::DyCards;
+#[cfg(feature = "dy_card")]
+pub use model::dycard::{CreateDyCard, DyCard, DyCardListFilter, UpdateDyCard};
+#[cfg(feature = "external")]
+pub use external::ExternalLinks;
+#[cfg(feature = "external")]
+pub use model::external::{
+    CreateExternalLink, ExternalLink, ExternalLinkListFilter, Style, UpdateExternalLink,
+};
+#[cfg(feature = "live_code")]
+pub use live_code::LiveCodes;
+#[cfg(feature = "live_code")]
+pub use model::livecode::{CreateLiveCode, LiveCode, LiveCodeListFilter, UpdateLiveCode};
+#[cfg(feature = "live_code_file")]
+pub use live_code_file::LiveCodeFiles;
+#[cfg(any(feature = "live_code", feature = "live_code_file"))]
+pub use model::livecodefile::{
+    CreateLiveCodeFile, FileType, LiveCodeFile, LiveCodeFileListFilter, UpdateLiveCodeFile,
+    UploadOptions, UploadedFile, DEFAULT_MAX_UPLOAD_SIZE,
+};
+pub use model::query::{ListFilter, ListQuery, SortBy, SortOrder};
+pub use model::{ApiResponse, FindOrCreate, Page, ResponseMeta};
+pub use batch::{BatchOptions, DEFAULT_BATCH_CONCURRENCY};
+pub use resource::Resource;
+pub use paginate::{ListStream, PageOptions, DEFAULT_PAGE_RETRIES, DEFAULT_PAGE_SIZE};
+pub use validate::{FieldError, Validate};
+#[cfg(feature = "short_link")]
+pub use model::shortlink::{CreateShortLink, ShortLink, ShortLinkListFilter, UpdateShortLink};
+#[cfg(feature = "short_link")]
+pub use short_link::ShortLinks;
+#[cfg(feature = "wx_card")]
+pub use model::wxcard::{CreateWxCard, UpdateWxCard, WxCard, WxCardListFilter};
+#[cfg(feature = "wx_card")]
+pub use wx_card::WxCards;
+
+///  这里是Vxwk项目对外开放的所有Api
+/// 简单使用案列
+/// ```no_run
+/// # #[cfg(feature = "short_link")]
+/// # mod example {
+/// use vxwk_rs_sdk::{ShortLinkListFilter, VxwkAPI, VxwkConfig};
+///
 /// pub fn main(){
-///     let confg = VxwkConfig{
-///         access_key: "开发者access_key".to_string(),
-///         access_secret: "开发者access_secret".to_owned(),
-///         endpoint: "节点baseutl".to_string(),
-///     };
-///     let vxwk_api = super::VxwkAPI::new(confg);
+///     let confg = VxwkConfig::new(
+///         "开发者access_key".to_string(),
+///         "开发者access_secret".to_owned(),
+///         "https://节点baseurl".to_string(),
+///     )
+///     // 可选：追加备用节点，priority 越小越优先
+///     .with_endpoint("https://备用节点baseurl", 10);
+///     let vxwk_api = VxwkAPI::new(confg).unwrap();
 ///     let runtime = tokio::runtime::Runtime::new().unwrap();
 ///     runtime.block_on(async{
-///         let res = vxwk_api.short_link_list(HashMap::new()).await;
+///         let res = vxwk_api.short_links().list(&ShortLinkListFilter::new()).await;
 ///         println!("{:?}",res);
 ///     });
-/// }```
+/// }
+/// # }
+/// # fn main() {}
+/// ```
 pub struct VxwkAPI {
     client: reqwest::Client,
     config: VxwkConfig,
+    nodes: NodePool,
     accept_header: Option<HashMap<String, String>>,
 }
 
-mod model {
-    pub(crate) mod livecodefile {
-        use serde::Serialize;
-        #[derive(Debug, Serialize)]
-        pub struct UpdateFileReq {
-            pub file: String,
-            pub name: String,
-        }
-        #[derive(Debug, Serialize)]
-        pub struct UpdateLiveCodeNameReq {
-            pub fid: String,
-            pub name: String,
-        }
-    }
-}
-
 mod timestamp {
     use std::time::{SystemTime, UNIX_EPOCH};
     pub fn get_timestamp() -> u64 {
@@ -62,9 +132,7 @@ mod timestamp {
             .duration_since(UNIX_EPOCH)
             .expect("SystemTime before UNIX EPOCH!");
         // 获取时间戳（以秒为单位）
-        let timestamp = duration.as_secs();
-
-        timestamp
+        duration.as_secs()
     }
 }
 
@@ -78,9 +146,7 @@ mod uhmac {
         mac.update(data.as_bytes());
         let result = mac.finalize();
         let code = result.into_bytes();
-        let hmac_base64 =
-            base64::engine::Engine::encode(&base64::engine::general_purpose::STANDARD, &code);
-        hmac_base64
+        base64::engine::Engine::encode(&base64::engine::general_purpose::STANDARD, code)
     }
 }
 
@@ -111,6 +177,284 @@ mod urand {
     }
 }
 
+mod endpoint {
+    use super::VxwkError;
+    use url::Url;
+
+    /// 经过校验的节点地址
+    ///
+    /// 支持带路径前缀的网关地址，例如 `https://gw.example.com/vxwk/`，
+    /// 末尾的 `/` 会被忽略，接口路径统一拼接在前缀之后。
+    #[derive(Debug, Clone)]
+    pub struct Endpoint {
+        base: Url,
+        prefix: String,
+    }
+
+    impl Endpoint {
+        pub fn parse(raw: &str) -> Result<Self, VxwkError> {
+            let raw = raw.trim();
+            if raw.is_empty() {
+                return Err(VxwkError::InvalidEndpoint("endpoint is empty".into()));
+            }
+            let base = Url::parse(raw)
+                .map_err(|err| VxwkError::InvalidEndpoint(format!("{}: {}", raw, err)))?;
+            if base.scheme() != "http" && base.scheme() != "https" {
+                return Err(VxwkError::InvalidEndpoint(format!(
+                    "{}: unsupported scheme `{}`",
+                    raw,
+                    base.scheme()
+                )));
+            }
+            if !base.has_host() {
+                return Err(VxwkError::InvalidEndpoint(format!("{}: missing host", raw)));
+            }
+            if base.query().is_some() || base.fragment().is_some() {
+                return Err(VxwkError::InvalidEndpoint(format!(
+                    "{}: query and fragment are not allowed",
+                    raw
+                )));
+            }
+            let prefix = base.path().trim_end_matches('/').to_string();
+            Ok(Self { base, prefix })
+        }
+
+        /// 将接口路径拼接到节点地址上
+        pub fn join(&self, path: &str) -> Url {
+            let mut url = self.base.clone();
+            url.set_path(&format!("{}/{}", self.prefix, path.trim_start_matches('/')));
+            url
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::Endpoint;
+
+        #[test]
+        fn test_join_without_prefix() {
+            for raw in ["https://vxwk.example.com", "https://vxwk.example.com/"] {
+                let endpoint = Endpoint::parse(raw).unwrap();
+                assert_eq!(
+                    endpoint.join("/api/v1/user/shortlink").as_str(),
+                    "https://vxwk.example.com/api/v1/user/shortlink"
+                );
+            }
+        }
+
+        #[test]
+        fn test_join_with_prefix() {
+            for raw in ["http://gw.example.com:8080/vxwk", "http://gw.example.com:8080/vxwk/"] {
+                let endpoint = Endpoint::parse(raw).unwrap();
+                assert_eq!(
+                    endpoint.join("/api/v1/user/shortlink").as_str(),
+                    "http://gw.example.com:8080/vxwk/api/v1/user/shortlink"
+                );
+            }
+        }
+
+        #[test]
+        fn test_invalid_endpoint() {
+            for raw in ["", "vxwk.example.com", "ftp://vxwk.example.com", "https://a.com/?x=1"] {
+                assert!(Endpoint::parse(raw).is_err(), "{} should be rejected", raw);
+            }
+        }
+    }
+}
+
+mod node {
+    use super::endpoint::Endpoint;
+    use super::{VxwkConfig, VxwkError};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    /// 节点当前的健康状态
+    #[derive(Debug, Clone)]
+    pub struct NodeStatus {
+        pub endpoint: String,
+        pub priority: u32,
+        /// 节点处于冷却期时为 `false`
+        pub available: bool,
+    }
+
+    pub(crate) struct Node {
+        pub(crate) raw: String,
+        pub(crate) endpoint: Endpoint,
+        priority: u32,
+        cooldown_until: Mutex<Option<Instant>>,
+    }
+
+    /// 按优先级排列的节点集合，请求失败的节点会进入冷却期
+    pub(crate) struct NodePool {
+        nodes: Vec<Node>,
+        cooldown: Duration,
+    }
+
+    impl NodePool {
+        pub fn from_config(config: &VxwkConfig) -> Result<Self, VxwkError> {
+            let mut nodes = Vec::new();
+            if !config.endpoint.trim().is_empty() || config.endpoints.is_empty() {
+                nodes.push(Node::new(&config.endpoint, 0)?);
+            }
+            for item in &config.endpoints {
+                nodes.push(Node::new(&item.endpoint, item.priority)?);
+            }
+            // 稳定排序，同优先级按配置顺序
+            nodes.sort_by_key(|node| node.priority);
+            Ok(Self {
+                nodes,
+                cooldown: config.node_cooldown,
+            })
+        }
+
+        /// 本次请求应依次尝试的节点
+        ///
+        /// 可用节点按优先级在前；冷却中的节点按冷却结束时间排在最后，
+        /// 保证所有节点都失败时仍会尝试一次
+        pub fn candidates(&self) -> Vec<&Node> {
+            let now = Instant::now();
+            let (mut available, mut cooling): (Vec<_>, Vec<_>) = self
+                .nodes
+                .iter()
+                .map(|node| (node, node.cooling_until(now)))
+                .partition(|(_, until)| until.is_none());
+            cooling.sort_by_key(|(_, until)| *until);
+            available.append(&mut cooling);
+            available.into_iter().map(|(node, _)| node).collect()
+        }
+
+        pub fn mark_failed(&self, node: &Node) {
+            log::warn!(
+                "vxwk node {} failed, cooling down for {:?}",
+                node.raw,
+                self.cooldown
+            );
+            *node.cooldown_until.lock().unwrap() = Some(Instant::now() + self.cooldown);
+        }
+
+        pub fn mark_served(&self, node: &Node) {
+            *node.cooldown_until.lock().unwrap() = None;
+        }
+
+        pub fn status(&self) -> Vec<NodeStatus> {
+            let now = Instant::now();
+            self.nodes
+                .iter()
+                .map(|node| NodeStatus {
+                    endpoint: node.raw.clone(),
+                    priority: node.priority,
+                    available: node.cooling_until(now).is_none(),
+                })
+                .collect()
+        }
+    }
+
+    impl Node {
+        fn new(raw: &str, priority: u32) -> Result<Self, VxwkError> {
+            Ok(Self {
+                raw: raw.trim().to_string(),
+                endpoint: Endpoint::parse(raw)?,
+                priority,
+                cooldown_until: Mutex::new(None),
+            })
+        }
+
+        fn cooling_until(&self, now: Instant) -> Option<Instant> {
+            (*self.cooldown_until.lock().unwrap()).filter(|until| *until > now)
+        }
+    }
+
+    #[cfg(test)]
+    mod test {
+        use super::NodePool;
+        use crate::VxwkConfig;
+        use std::time::Duration;
+
+        fn pool() -> NodePool {
+            let config = VxwkConfig::new(
+                "key".into(),
+                "secret".into(),
+                "https://primary.example.com".into(),
+            )
+            .with_endpoint("https://backup-b.example.com", 20)
+            .with_endpoint("https://backup-a.example.com", 10)
+            .with_node_cooldown(Duration::from_secs(60));
+            NodePool::from_config(&config).unwrap()
+        }
+
+        fn order(pool: &NodePool) -> Vec<&str> {
+            pool.candidates().into_iter().map(|node| node.raw.as_str()).collect()
+        }
+
+        #[test]
+        fn test_candidates_by_priority() {
+            let pool = pool();
+            assert_eq!(
+                order(&pool),
+                [
+                    "https://primary.example.com",
+                    "https://backup-a.example.com",
+                    "https://backup-b.example.com"
+                ]
+            );
+        }
+
+        #[test]
+        fn test_failed_node_goes_last() {
+            let pool = pool();
+            let primary = pool.candidates()[0];
+            pool.mark_failed(primary);
+            assert_eq!(order(&pool)[2], "https://primary.example.com");
+            assert!(!pool.status()[0].available);
+
+            pool.mark_served(primary);
+            assert_eq!(order(&pool)[0], "https://primary.example.com");
+            assert!(pool.status()[0].available);
+        }
+    }
+}
+
+mod telemetry {
+    //! 可选的 tracing / metrics 输出，未开启对应 feature 时为空操作
+    use std::time::Duration;
+
+    /// 记录一次请求结果，`status` 为 `None` 表示连接失败
+    #[allow(unused_variables)]
+    pub fn record_request(path: &str, node: &str, status: Option<u16>, elapsed: Duration) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            target: "vxwk",
+            path,
+            node,
+            status,
+            elapsed_ms = elapsed.as_millis() as u64,
+            "vxwk request finished"
+        );
+        #[cfg(feature = "metrics")]
+        {
+            let status = status.map_or_else(|| "error".to_string(), |code| code.to_string());
+            metrics::counter!(
+                "vxwk_requests_total",
+                "path" => path.to_string(),
+                "node" => node.to_string(),
+                "status" => status
+            )
+            .increment(1);
+            metrics::histogram!("vxwk_request_duration_seconds", "path" => path.to_string())
+                .record(elapsed.as_secs_f64());
+        }
+    }
+
+    /// 记录一次节点切换
+    #[allow(unused_variables)]
+    pub fn record_failover(node: &str) {
+        #[cfg(feature = "tracing")]
+        tracing::warn!(target: "vxwk", node, "vxwk node failed, switching to next node");
+        #[cfg(feature = "metrics")]
+        metrics::counter!("vxwk_node_failures_total", "node" => node.to_string()).increment(1);
+    }
+}
+
 fn get_query(query: HashMap<&str, &str>) -> String {
     let mut target: Vec<_> = query.into_iter().collect();
     target.sort_by(|a, b| a.0.cmp(b.0));
@@ -121,11 +465,49 @@ fn get_query(query: HashMap<&str, &str>) -> String {
     encoded_query
 }
 
-#[derive(Debug, Clone)]
+pub use node::NodeStatus;
+
+/// 节点失败后的默认冷却时间
+pub const DEFAULT_NODE_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// 幂等创建时携带幂等键的请求头，服务端不支持时会被忽略
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// 每个请求收到响应后的回调，参数为接口路径与响应元信息
+pub type ResponseHook = Arc<dyn Fn(&str, &ResponseMeta) + Send + Sync>;
+
+#[derive(Clone)]
 pub struct VxwkConfig {
     pub access_key: String,
     pub access_secret: String,
+    /// 主节点，优先级为 0
+    pub endpoint: String,
+    /// 其他节点，连接失败时按优先级依次切换，5xx 的处理见 [`VxwkAPI`] 文档
+    pub endpoints: Vec<VxwkEndpoint>,
+    /// 节点失败后的冷却时间，冷却期内优先使用其他节点
+    pub node_cooldown: Duration,
+    /// 响应回调，可用于记录返回原始 JSON 的接口由哪个节点处理
+    pub on_response: Option<ResponseHook>,
+}
+
+impl fmt::Debug for VxwkConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VxwkConfig")
+            .field("access_key", &self.access_key)
+            .field("access_secret", &self.access_secret)
+            .field("endpoint", &self.endpoint)
+            .field("endpoints", &self.endpoints)
+            .field("node_cooldown", &self.node_cooldown)
+            .field("on_response", &self.on_response.is_some())
+            .finish()
+    }
+}
+
+/// 带优先级的节点，priority 越小越优先
+#[derive(Debug, Clone)]
+pub struct VxwkEndpoint {
     pub endpoint: String,
+    pub priority: u32,
 }
 
 #[derive(Debug, Error)]
@@ -148,6 +530,14 @@ pub enum VxwkError {
     InvalidAccessSecret(String),
     #[error("Invalid Endpoint `{0}`")]
     InvalidEndpoint(String),
+    #[error("api error `{code}`: {msg}")]
+    Api { code: i64, msg: String },
+    #[error("Invalid fields `{}`", validate::join(.0))]
+    Validation(Vec<FieldError>),
+    #[error("Skipped after an earlier batch item failed")]
+    Skipped,
+    #[error("io error")]
+    IoError(#[from] std::io::Error),
 }
 
 impl VxwkConfig {
@@ -156,24 +546,59 @@ impl VxwkConfig {
             access_key,
             access_secret,
             endpoint,
+            endpoints: Vec::new(),
+            node_cooldown: DEFAULT_NODE_COOLDOWN,
+            on_response: None,
         }
     }
+
+    /// 追加一个节点
+    pub fn with_endpoint(mut self, endpoint: &str, priority: u32) -> Self {
+        self.endpoints.push(VxwkEndpoint {
+            endpoint: endpoint.to_string(),
+            priority,
+        });
+        self
+    }
+
+    /// 设置节点失败后的冷却时间
+    pub fn with_node_cooldown(mut self, cooldown: Duration) -> Self {
+        self.node_cooldown = cooldown;
+        self
+    }
+
+    /// 设置响应回调，每个请求收到响应后调用一次，并发请求时也能对应到具体节点
+    pub fn with_on_response<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&str, &ResponseMeta) + Send + Sync + 'static,
+    {
+        self.on_response = Some(Arc::new(hook));
+        self
+    }
 }
 
 
 
 impl VxwkAPI {
-    pub fn new(config: VxwkConfig) -> Self {
+    /// 创建客户端，节点地址在此处统一校验
+    pub fn new(config: VxwkConfig) -> Result<Self, VxwkError> {
+        let nodes = NodePool::from_config(&config)?;
         let client = reqwest::Client::new();
         let accept_header = Some(HashMap::from([(
             String::from("Accept"),
             String::from("application/json"),
         )]));
-        Self {
+        Ok(Self {
             client,
             config,
+            nodes,
             accept_header,
-        }
+        })
+    }
+
+    /// 所有节点的健康状态
+    pub fn node_status(&self) -> Vec<NodeStatus> {
+        self.nodes.status()
     }
     fn gen_signature(
         &self,
@@ -193,22 +618,22 @@ impl VxwkAPI {
         )
     }
 
-    /// 用于发送GET请求
-    async fn get(
+    /// 构建带签名参数的完整请求地址
+    ///
+    /// 签名使用的是接口路径本身，不包含节点地址中的路径前缀
+    ///
+    /// 返回请求地址与本次请求使用的随机数 `xn`
+    fn signed_url(
         &self,
+        node: &Node,
         path: &str,
         query_params: HashMap<&str, &str>,
-    ) -> Result<Response, VxwkError> {
-        // 构建完整的URL
-        let mut url = Url::parse(&self.config.endpoint)?;
-        url.set_path(path);
-        url.query_pairs_mut().extend_pairs(query_params.iter());
-
+    ) -> (Url, String) {
         // 构建签名所需的参数
         let timestamp = format!("{}", timestamp::get_timestamp());
         let unique_number = generate_unique_number(18);
 
-        let mut signature_params = query_params.clone();
+        let mut signature_params = query_params;
         signature_params.insert("xaccesskey", self.config.access_key.as_str());
         signature_params.insert("xn", &unique_number);
         signature_params.insert("xtimestamp", &timestamp);
@@ -222,489 +647,300 @@ impl VxwkAPI {
         signature_params.insert("xsign", &sign);
 
         // 添加签名到URL
+        let mut url = node.endpoint.join(path);
         url.query_pairs_mut().extend_pairs(signature_params.iter());
+        (url, unique_number)
+    }
 
-        // 发起GET请求
-        let response = self.client.get(url).send().await?;
-
-        // 获取响应体
-        match response.error_for_status_ref() {
-            Ok(_) => Ok(response),
-            Err(err) => {
-                log::error!("Error: {}", err);
-                Err(VxwkError::from(err))
+    fn with_accept_header(&self, mut builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        if let Some(headers) = &self.accept_header {
+            for (key, value) in headers {
+                builder = builder.header(key, value);
             }
         }
+        builder
     }
-    /// 用于发送POST请求
-    async fn post<T>(&self, path: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+
+    /// 按节点优先级发送请求，连接失败时切换到下一个节点
+    ///
+    /// 返回 5xx 时，GET 请求与携带幂等键的请求会切换节点；其它 POST 可能已在服务端生效，
+    /// 只有 503 才会切换，避免网关超时后在另一个节点上重复创建
+    async fn send<F>(
+        &self,
+        path: &str,
+        query_params: HashMap<&str, &str>,
+        build: F,
+    ) -> Result<(Response, ResponseMeta), VxwkError>
     where
-        T: Serialize,
+        F: Fn(Url) -> reqwest::RequestBuilder,
     {
-        let mut url = self.config.endpoint.clone();
-        url.push_str(path);
-
-        let timestamp = format!("{}", timestamp::get_timestamp());
-        let unique_number = generate_unique_number(18);
-
-        let mut query_params = HashMap::new();
-        query_params.insert("xaccesskey", self.config.access_key.as_str());
-        query_params.insert("xn", &unique_number);
-        query_params.insert("xtimestamp", timestamp.as_str());
-        query_params.insert("xrunmode", "release");
-
-        let signature = self.gen_signature(
-            timestamp.as_str(),
-            unique_number.as_str(),
-            path,
-            query_params.clone(),
-        );
-        query_params.insert("xsignature", signature.as_str());
-
-        let sign = uhmac::calculate_hmac(&self.config.access_secret, &signature);
-        query_params.insert("xsign", sign.as_str());
-
-        // 构建URL
-        let url_with_params = Url::parse_with_params(&url, query_params)?;
-
-        // 发起POST请求
-        let response = self.client.post(url_with_params).json(body).send().await?;
-
-        // 获取响应体
-        match response.error_for_status_ref() {
-            Ok(_) => Ok(response.json().await?),
-            Err(err) => {
+        let mut last_error = None;
+        for node in self.nodes.candidates() {
+            let (url, nonce) = self.signed_url(node, path, query_params.clone());
+            let request = self.with_accept_header(build(url)).build()?;
+            let replayable = request.method() == Method::GET
+                || request.headers().contains_key(IDEMPOTENCY_KEY_HEADER);
+            let started = Instant::now();
+            let result = self.client.execute(request).await;
+            let latency = started.elapsed();
+            telemetry::record_request(
+                path,
+                &node.raw,
+                result.as_ref().ok().map(|response| response.status().as_u16()),
+                latency,
+            );
+            match result {
+                Ok(response) if response.status().is_server_error() => {
+                    self.nodes.mark_failed(node);
+                    let status = response.status();
+                    let err = response.error_for_status().err();
+                    if !replayable && status != StatusCode::SERVICE_UNAVAILABLE {
+                        if let Some(err) = err {
+                            log::error!("Error: {}", err);
+                            return Err(VxwkError::from(err));
+                        }
+                    }
+                    telemetry::record_failover(&node.raw);
+                    last_error = err;
+                }
+                Ok(response) => {
+                    log::debug!("vxwk request {} served by {}", path, node.raw);
+                    self.nodes.mark_served(node);
+                    let meta = ResponseMeta {
+                        status: response.status(),
+                        headers: response.headers().clone(),
+                        nonce,
+                        node: node.raw.clone(),
+                        latency,
+                    };
+                    if let Some(hook) = &self.config.on_response {
+                        hook(path, &meta);
+                    }
+                    // 获取响应体
+                    return match response.error_for_status_ref() {
+                        Ok(_) => Ok((response, meta)),
+                        Err(err) => {
+                            log::error!("Error: {}", err);
+                            Err(VxwkError::from(err))
+                        }
+                    };
+                }
+                Err(err) if err.is_connect() => {
+                    self.nodes.mark_failed(node);
+                    telemetry::record_failover(&node.raw);
+                    last_error = Some(err);
+                }
+                Err(err) => return Err(VxwkError::from(err)),
+            }
+        }
+        match last_error {
+            Some(err) => {
                 log::error!("Error: {}", err);
                 Err(VxwkError::from(err))
             }
+            None => Err(VxwkError::InvalidEndpoint("no endpoint available".into())),
         }
     }
 
-    //抖音卡片
-    pub async fn dy_card_img_url(
-        &self,
-        id: &str,
-        opt: Option<HashMap<&str, &str>>,
-    ) -> Result<String, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("projectid", id);
-        if let Some(opt_map) = opt {
-            // 如果有传入的Option参数，将其合并到query_params中
-            query_params.extend(opt_map.iter().map(|(k, v)| (k, v)));
-        }
-        let result = self.get("/api/v1/user/carddy/img", query_params).await?;
-        let file_url = result.headers().get("Location");
-        if let Some(file_url) = file_url {
-            return Ok(file_url.to_str().unwrap().to_string());
-        }
-        Err(VxwkError::InvalidRequest("get url fail".into()))
-    }
-    /// 抖音卡片列表
-    pub async fn dy_card_get_list(
-        &self,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let result = self.get("/api/v1/user/carddy/list", opt).await?;
-        return Ok(result.json().await?);
-    }
-    /// 抖音卡片详情
-    pub async fn dy_card_get_info(
-        &self,
-        id: &str,
-        opt: Option<HashMap<&str, &str>>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        if let Some(opt_map) = opt {
-            // 如果有传入的Option参数，将其合并到query_params中
-            query_params.extend(opt_map.iter().map(|(k, v)| (k, v)));
-        }
-        let result = self.get("/api/v1/user/carddy", query_params).await?;
-        return Ok(result.json().await?);
-    }
-    /// 抖音卡片创建
-    pub async fn dy_card_create(
-        &self,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let result = self.post("/api/v1/user/carddy", &opt).await?;
-        return Ok(result);
-    }
-
-    /// 抖音卡片修改
-    pub async fn dy_card_update(
-        &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self
-            .post("/api/v1/user/carddy/update", &query_params)
-            .await?;
-        return Ok(result);
-    }
-    /// 抖音卡片删除
-    pub async fn dy_card_delete(
-        &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self
-            .post("/api/v1/user/carddy/delete", &query_params)
-            .await?;
-        return Ok(result);
-    }
-    ///微信卡片
-    pub async fn wx_card_img_url(
-        &self,
-        id: &str,
-        opt: Option<HashMap<&str, &str>>,
-    ) -> Result<String, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("projectid", id);
-        if let Some(opt_map) = opt {
-            // 如果有传入的Option参数，将其合并到query_params中
-            query_params.extend(opt_map.iter().map(|(k, v)| (k, v)));
-        }
-        let result = self.get("/api/v1/user/wxcard/img", query_params).await?;
-        let file_url = result.headers().get("Location");
-        if let Some(file_url) = file_url {
-            return Ok(file_url.to_str().unwrap().to_string());
-        }
-        Err(VxwkError::InvalidRequest("get url fail".into()))
-    }
-    /// 微信卡片详情
-    pub async fn wx_card_list(
-        &self,
-        opt_map: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let result = self.get("/api/v1/user/wxcard", opt_map).await?;
-        return Ok(result.json().await?);
-    }
-    /// 创建微信卡片
-    pub async fn wx_card_create(
-        &self,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let result = self.post("/api/v1/user/wxcard", &opt).await?;
-        return Ok(result);
-    }
-    /// 更新微信卡片
-    pub async fn wx_card_update(
-        &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self
-            .post("/api/v1/user/wxcard/update", &query_params)
-            .await?;
-        return Ok(result);
-    }
-    /// 删除微信卡片
-    pub async fn wx_card_delete(
-        &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self
-            .post("/api/v1/user/wxcard/delete", &query_params)
-            .await?;
-        return Ok(result);
-    }
-
-    /// 获取微信卡片详情
-    pub async fn wx_card_info(
-        &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self.get("/api/v1/user/cardwx", query_params).await?;
-        return Ok(result.json().await?);
-    }
-    //// 活码
-    ///  获取活码列表
-    pub async fn live_code_list(
-        &self,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let result = self.get("/api/v1/user/livecode/list", opt).await?;
-        return Ok(result.json().await?);
-    }
-    /// 创建活码
-    pub async fn live_code_create(
-        &self,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let result = self.post("/api/v1/user/livecode/create", &opt).await?;
-        return Ok(result);
-    }
-    /// 更新活码信息
-    pub async fn live_code_update(
-        &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self
-            .post("/api/v1/user/livecode/update", &query_params)
-            .await?;
-        return Ok(result);
-    }
-    /// 删除活码
-    pub async fn live_code_delete(
-        &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self
-            .post("/api/v1/user/livecode/delete", &query_params)
-            .await?;
-        return Ok(result);
-    }
-    /// 查询活码信息
-    pub async fn live_code_info(
+    /// 用于发送GET请求
+    #[cfg(any(
+        feature = "dy_card",
+        feature = "wx_card",
+        feature = "live_code",
+        feature = "live_code_file",
+        feature = "external",
+        feature = "short_link"
+    ))]
+    async fn get(
         &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self
-            .post("/api/v1/user/livecode/info", &query_params)
-            .await?;
-        return Ok(result);
+        path: &str,
+        query_params: HashMap<&str, &str>,
+    ) -> Result<Response, VxwkError> {
+        let (response, _) = self.get_with_meta(path, query_params).await?;
+        Ok(response)
     }
-    /// 活码文件
-    /// 获取活码文件列表
-    pub async fn live_code_file_url_list(
+    /// 用于发送GET请求，同时返回响应元信息
+    async fn get_with_meta(
         &self,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let result = self.get("/api/v1/user/livecode/file/list", opt).await?;
-        return Ok(result.json().await?);
+        path: &str,
+        query_params: HashMap<&str, &str>,
+    ) -> Result<(Response, ResponseMeta), VxwkError> {
+        self.send(path, query_params, |url| self.client.get(url)).await
     }
-    /// 获取活码文件url
-    pub async fn live_code_file_url(
-        &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self.get("/api/v1/user/livecode/file", query_params).await?;
-        return Ok(result.json().await?);
-    }
-    /// 上传文件
-    pub async fn live_code_file_upload(
-        &self,
-        file: Vec<u8>,
-        name: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let engine = engine::GeneralPurpose::new(&alphabet::STANDARD, engine::general_purpose::PAD);
-        let file_base64 = engine.encode(file);
-        let req = UpdateFileReq {
-            file: format!("base64:{}", file_base64),
-            name: name.to_owned(),
-        };
-        let result = self.post("/api/v1/user/livecode/file/update", &req).await?;
-        return Ok(result);
-    }
-
-    /// 修改活码名称
-    pub async fn live_code_file_name_update(
-        &self,
-        id: &str,
-        name: &str,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let req = UpdateLiveCodeNameReq {
-            fid: id.to_string(),
-            name: name.to_owned(),
-        };
-        let result = self
-            .post("/api/v1/user/livecode/file/name/update", &req)
-            .await?;
-        return Ok(result);
+    /// 用于发送POST请求
+    #[cfg(any(
+        feature = "dy_card",
+        feature = "wx_card",
+        feature = "live_code",
+        feature = "live_code_file",
+        feature = "external",
+        feature = "short_link"
+    ))]
+    async fn post<T>(&self, path: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let (value, _) = self.post_with_meta(path, body).await?;
+        Ok(value)
     }
-
-    //删除活码
-    pub async fn live_code_file_delete(
+    /// 用于发送POST请求，同时返回响应元信息
+    async fn post_with_meta<T>(
         &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self
-            .post("/api/v1/user/livecode/file/delete", &query_params)
+        path: &str,
+        body: &T,
+    ) -> Result<(serde_json::Value, ResponseMeta), VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        let (response, meta) = self
+            .send(path, HashMap::new(), |url| self.client.post(url).json(body))
             .await?;
-        return Ok(result);
+        Ok((response.json().await?, meta))
     }
-    ///外链
-    /// 查询外联显示logo
-    pub async fn external_logo_url(
+    /// 发送GET请求并解析为 [`ApiResponse`]
+    async fn get_typed<D>(
         &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<String, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("projectid", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self.get("/api/v1/admin/external/img", query_params).await?;
-        let file_url = &result.headers().get("Location");
-        if let Some(file_url) = file_url {
-            return Ok(file_url.to_str()?.to_string());
-        }
-        Err(VxwkError::InvalidRequest("get url fail".into()))
-    }
-
-    /// 外链列表
-    pub async fn external_url_list(
-        &self,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let result = self.get("/api/v1/user/external/list", opt).await?;
-        return Ok(result.json().await?);
+        path: &str,
+        query_params: HashMap<&str, &str>,
+    ) -> Result<ApiResponse<D>, VxwkError>
+    where
+        D: DeserializeOwned,
+    {
+        let (response, meta) = self.get_with_meta(path, query_params).await?;
+        ApiResponse::decode(response.json().await?, meta)
     }
-
-    /// 获取外链详情
-    pub async fn external_url_info(
-        &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self.get("/api/v1/user/external", query_params).await?;
-        return Ok(result.json().await?);
-    }
-
-    /// 添加外链
-    /// ```
-    /// {
-    ///   "domainID": "some-uuid-string",
-    ///   "title": "ShortLinkTitle",
-    ///   "describe": "ShortLinkDescription",
-    ///   "tips": "OptionalTips",
-    ///   "img": null,
-    ///   "type": 1,
-    ///   "startAt": 0,
-    ///   "stopAt": 0,
-    ///   "link": [],
-    ///   "hash": "HashValue",
-    ///   "testMode": true,
-    ///   "style": "SomeStyle"
-    /// }
-    /// ```
-    // 请注意：
-    // - "domainID" 是一个 UUID 字符串，所以需要按照 UUID 的格式提供。
-    // - "title" 最大长度为 20。
-    // - "describe" 最大长度为 100。
-    // - "tips" 最大长度为 100，是可选的。
-    // - "img" 是可选的，这里设置为 `null`。
-    // - "type" 是一个整数。
-    // - "startAt" 和 "stopAt" 是可选的，设置为 0。
-    // - "link" 是一个数组，这里为空数组。
-    // - "hash" 最大长度为 100，是可选的。
-    // - "testMode" 是一个布尔值。
-    // - "style" 需要符合 "Style" 的格式。
-    pub async fn external_url_create(
-        &self,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let result = self.post("/api/v1/user/external/create", &opt).await?;
-        return Ok(result);
+    /// 校验筛选条件，展开为查询参数后发送GET请求
+    async fn list_typed<F, D>(&self, path: &str, filter: &F) -> Result<ApiResponse<D>, VxwkError>
+    where
+        F: ListFilter,
+        D: DeserializeOwned,
+    {
+        filter.list_query().validate()?;
+        let pairs = model::query_pairs(filter)?;
+        let query_params = pairs.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        self.get_typed(path, query_params).await
     }
-
-    /// 修改外联
-    pub async fn external_url_update(&self,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let result = self.post("/api/v1/user/external/update", &opt).await?;
-        return Ok(result);
+    /// 发送POST请求并解析为 [`ApiResponse`]
+    async fn post_typed<T, D>(&self, path: &str, body: &T) -> Result<ApiResponse<D>, VxwkError>
+    where
+        T: Serialize,
+        D: DeserializeOwned,
+    {
+        let (value, meta) = self.post_with_meta(path, body).await?;
+        ApiResponse::decode(value, meta)
     }
-
-    ///外链删除
-    pub async fn external_url_logo_delete(
+    /// 发送带额外请求头的POST请求并解析为 [`ApiResponse`]
+    #[cfg(feature = "short_link")]
+    async fn post_typed_with_headers<T, D>(
         &self,
-        id: &str,
-        opt: HashMap<&str, &str>,
-    ) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self
-            .post("/api/v1/user/external/delete", &query_params)
-            .await?;
-        return Ok(result);
-    }
-
-    /// 以下部分为短链相关api
-    /// 生成短连接
-    pub async fn short_link_list(&self,opt: HashMap<&str, &str>)-> Result<serde_json::Value, VxwkError>{
-        let result = self.get("/api/v1/user/shortlink/list", opt).await?;
-        return Ok(result.json().await?);
-    }
-    
-    /// 获取短连接详情
-    pub async fn short_link_detail(&self, id: &str, opt: HashMap<&str, &str>) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self
-            .get("/api/v1/user/shortlink", query_params)
-            .await?;
-        return Ok(result.json().await?);
-    }
-    /// 创建新的短链
-    pub async fn short_link_create(&self, opt: HashMap<&str, &str>) -> Result<serde_json::Value, VxwkError> {
-        let result = self.post("/api/v1/user/shortlink/create", &opt).await?;
-        return Ok(result);
-    }
-
-    /// 更新短链
-    pub async fn short_link_update(&self, id: &str, opt: HashMap<&str, &str>) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        query_params.extend(opt.iter().map(|(k, v)| (k, v)));
-        let result = self
-            .post("/api/v1/user/shortlink/update", &query_params)
+        path: &str,
+        body: &T,
+        headers: reqwest::header::HeaderMap,
+    ) -> Result<ApiResponse<D>, VxwkError>
+    where
+        T: Serialize,
+        D: DeserializeOwned,
+    {
+        let (response, meta) = self
+            .send(path, HashMap::new(), |url| {
+                self.client.post(url).headers(headers.clone()).json(body)
+            })
             .await?;
-        return Ok(result);
+        ApiResponse::decode(response.json().await?, meta)
     }
+}
 
-
-    /// 删除短链
-    /// 
-    pub async fn short_link_delete(&self, id: &str) -> Result<serde_json::Value, VxwkError> {
-        let mut query_params = HashMap::new();
-        query_params.insert("id", id);
-        let result = self
-            .post("/api/v1/user/shortlink/delete", &query_params)
-            .await?;
-        return Ok(result);
+#[cfg(test)]
+mod test {
+    use crate::{VxwkAPI, VxwkConfig, VxwkError};
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// 本地节点，`respond` 根据请求行（如 `POST /api/v1/... HTTP/1.1`）返回状态与响应体
+    pub(crate) async fn serve<F>(respond: F) -> String
+    where
+        F: Fn(&str) -> (&'static str, &'static str) + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = format!("http://{}", listener.local_addr().unwrap());
+        let respond = Arc::new(respond);
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let respond = respond.clone();
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buf = [0; 4096];
+                    // 读完请求头与请求体后再响应
+                    loop {
+                        let n = socket.read(&mut buf).await.unwrap_or(0);
+                        request.extend_from_slice(&buf[..n]);
+                        let text = String::from_utf8_lossy(&request).to_lowercase();
+                        if let Some(end) = text.find("\r\n\r\n") {
+                            let len = text
+                                .lines()
+                                .find_map(|line| line.strip_prefix("content-length:"))
+                                .and_then(|len| len.trim().parse::<usize>().ok())
+                                .unwrap_or(0);
+                            if n == 0 || request.len() >= end + 4 + len {
+                                break;
+                            }
+                        } else if n == 0 {
+                            break;
+                        }
+                    }
+                    let text = String::from_utf8_lossy(&request);
+                    let (status, body) = respond(text.lines().next().unwrap_or_default());
+                    let response = format!(
+                        "HTTP/1.1 {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        addr
+    }
+
+    /// 对每个请求返回固定响应的本地节点，返回地址与收到的请求数
+    async fn node(status: &'static str, body: &'static str) -> (String, Arc<AtomicUsize>) {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let addr = serve(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            (status, body)
+        })
+        .await;
+        (addr, hits)
+    }
+
+    #[tokio::test]
+    async fn test_post_not_replayed_after_gateway_error() {
+        let (primary, primary_hits) = node("502 Bad Gateway", "").await;
+        let (backup, backup_hits) = node("200 OK", r#"{"code":200,"data":{}}"#).await;
+        let served = Arc::new(Mutex::new(Vec::new()));
+        let record = served.clone();
+        let config = VxwkConfig::new("key".into(), "secret".into(), primary)
+            .with_endpoint(&backup, 10)
+            .with_on_response(move |_, meta| record.lock().unwrap().push(meta.node.clone()));
+        let api = VxwkAPI::new(config).unwrap();
+
+        let res = api
+            .post_with_meta("/api/v1/test/create", &serde_json::json!({}))
+            .await;
+        assert!(matches!(res, Err(VxwkError::ReqwestError(_))));
+        assert_eq!(primary_hits.load(Ordering::SeqCst), 1);
+        assert_eq!(backup_hits.load(Ordering::SeqCst), 0);
+
+        // 主节点冷却中，请求由备用节点处理，回调能拿到实际处理的节点
+        api.get_with_meta("/api/v1/test/list", HashMap::new())
+            .await
+            .unwrap();
+        assert_eq!(backup_hits.load(Ordering::SeqCst), 1);
+        assert_eq!(*served.lock().unwrap(), [backup]);
     }
 }
@@ -0,0 +1,181 @@
+//! 活码相关api
+use crate::batch::BatchOptions;
+use crate::model::livecode::{CreateLiveCode, LiveCode, LiveCodeListFilter, UpdateLiveCode};
+use crate::model::{with_id, ApiResponse, Page};
+use crate::paginate::{ListStream, PageOptions};
+use crate::resource::{self, LiveCodeResource, Resource};
+use crate::{VxwkAPI, VxwkError};
+use serde::Serialize;
+use std::collections::HashMap;
+
+impl VxwkAPI {
+    /// 获取活码列表
+    #[deprecated(note = "use `live_codes().list()` instead")]
+    pub async fn live_code_list(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let result = self.get("/api/v1/user/livecode/list", opt).await?;
+        Ok(result.json().await?)
+    }
+    /// 创建活码
+    #[deprecated(note = "use `live_codes().create()` instead")]
+    pub async fn live_code_create(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let result = self.post("/api/v1/user/livecode/create", &opt).await?;
+        Ok(result)
+    }
+    /// 更新活码信息
+    #[deprecated(note = "use `live_codes().update()` instead")]
+    pub async fn live_code_update(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self
+            .post("/api/v1/user/livecode/update", &query_params)
+            .await?;
+        Ok(result)
+    }
+    /// 删除活码
+    #[deprecated(note = "use `live_codes().delete()` instead")]
+    pub async fn live_code_delete(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self
+            .post("/api/v1/user/livecode/delete", &query_params)
+            .await?;
+        Ok(result)
+    }
+    /// 查询活码信息
+    #[deprecated(note = "use `live_codes().get()` instead")]
+    pub async fn live_code_info(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self
+            .post("/api/v1/user/livecode/info", &query_params)
+            .await?;
+        Ok(result)
+    }
+
+    /// 活码接口
+    pub fn live_codes(&self) -> LiveCodes<'_> {
+        LiveCodes { api: self }
+    }
+}
+
+/// 活码接口，通过 [`VxwkAPI::live_codes`] 获取
+#[derive(Clone, Copy)]
+pub struct LiveCodes<'a> {
+    api: &'a VxwkAPI,
+}
+
+impl<'a> LiveCodes<'a> {
+    /// 活码列表，按类型化的筛选条件查询
+    pub async fn list(
+        &self,
+        filter: &LiveCodeListFilter,
+    ) -> Result<ApiResponse<Page<LiveCode>>, VxwkError> {
+        resource::list::<LiveCodeResource>(self.api, filter).await
+    }
+
+    /// 按筛选条件逐页拉取全部活码，返回按需请求的流
+    ///
+    /// `filter` 中的分页字段会被 `opt` 覆盖
+    pub fn stream(self, filter: LiveCodeListFilter, opt: PageOptions) -> ListStream<'a, LiveCode> {
+        resource::stream::<LiveCodeResource>(self.api, filter, opt)
+    }
+
+    /// 活码详情
+    pub async fn get(&self, id: &str) -> Result<ApiResponse<LiveCode>, VxwkError> {
+        resource::get::<LiveCodeResource>(self.api, id).await
+    }
+
+    /// 创建活码，发送前校验字段
+    pub async fn create(&self, req: &CreateLiveCode) -> Result<ApiResponse<LiveCode>, VxwkError> {
+        resource::create::<LiveCodeResource>(self.api, req).await
+    }
+
+    /// 更新活码信息，发送前校验字段，只发送 `req` 中设置过的字段
+    pub async fn update(
+        &self,
+        id: &str,
+        req: &UpdateLiveCode,
+    ) -> Result<ApiResponse<()>, VxwkError> {
+        resource::update::<LiveCodeResource>(self.api, id, req).await
+    }
+
+    /// 删除活码
+    pub async fn delete(&self, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+        resource::delete::<LiveCodeResource>(self.api, id).await
+    }
+
+    /// 创建活码，请求体可以是任意可序列化的结构或 `serde_json::Value`
+    pub async fn create_json<T>(&self, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api.post(LiveCodeResource::CREATE_PATH, body).await
+    }
+
+    /// 更新活码，`id` 会合并进请求体，其它字段保留原本的 JSON 类型
+    pub async fn update_json<T>(&self, id: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .post(LiveCodeResource::UPDATE_PATH, &with_id(id, body)?)
+            .await
+    }
+
+    /// 批量创建活码，按输入顺序返回每一项的结果
+    pub async fn batch_create(
+        &self,
+        reqs: &[CreateLiveCode],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<LiveCode>, VxwkError>> {
+        resource::batch_create::<LiveCodeResource>(self.api, reqs, opt).await
+    }
+
+    /// 批量更新活码，`items` 为 `(id, 更新请求)`
+    pub async fn batch_update<S: AsRef<str>>(
+        &self,
+        items: &[(S, UpdateLiveCode)],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        resource::batch_update::<LiveCodeResource, S>(self.api, items, opt).await
+    }
+
+    /// 批量删除活码
+    pub async fn batch_delete<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        resource::batch_delete::<LiveCodeResource, S>(self.api, ids, opt).await
+    }
+
+    /// 批量获取活码详情
+    pub async fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<LiveCode>, VxwkError>> {
+        resource::get_many::<LiveCodeResource, S>(self.api, ids, opt).await
+    }
+}
@@ -0,0 +1,297 @@
+//! 活码文件相关api
+use crate::batch::BatchOptions;
+#[cfg(feature = "stream_upload")]
+use crate::model::livecodefile::{check_file, too_large};
+use crate::model::livecodefile::{
+    CreateLiveCodeFile, LiveCodeFile, LiveCodeFileListFilter, UpdateLiveCodeFile,
+    UpdateLiveCodeNameReq, UploadOptions, UploadedFile,
+};
+#[cfg(feature = "stream_upload")]
+use crate::model::livecodefile::SNIFF_LEN;
+use crate::model::{ApiResponse, Page};
+use crate::paginate::{ListStream, PageOptions};
+use crate::resource::{self, LiveCodeFileResource};
+#[cfg(feature = "stream_upload")]
+use crate::upload::LazyFile;
+use crate::validate::Validate;
+#[cfg(feature = "stream_upload")]
+use crate::validate::Validator;
+use crate::{VxwkAPI, VxwkError};
+use std::collections::HashMap;
+#[cfg(feature = "stream_upload")]
+use std::io;
+#[cfg(feature = "stream_upload")]
+use std::path::Path;
+#[cfg(feature = "stream_upload")]
+use std::sync::Mutex;
+#[cfg(feature = "stream_upload")]
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// 旧接口的 `opt`：`maxSize` 设置大小上限，`contentType` 为 `auto` 时附带识别出的类型，其余字段原样放入请求体
+fn legacy_upload_body(
+    file: Vec<u8>,
+    name: &str,
+    mut opt: HashMap<&str, &str>,
+) -> Result<serde_json::Value, VxwkError> {
+    let mut upload = UploadOptions::default();
+    if let Some(max_size) = opt.remove("maxSize") {
+        upload.max_size = max_size.parse().map_err(|_| {
+            VxwkError::InvalidRequest(format!("invalid maxSize `{}`", max_size))
+        })?;
+    }
+    if opt.get("contentType") == Some(&"auto") {
+        opt.remove("contentType");
+        upload.content_type = true;
+    }
+    let req = CreateLiveCodeFile::new(name, file).with_options(upload);
+    req.validate()?;
+    let mut body =
+        serde_json::to_value(&req).map_err(|err| VxwkError::InvalidRequest(err.to_string()))?;
+    if let Some(body) = body.as_object_mut() {
+        for (key, value) in opt {
+            body.entry(key)
+                .or_insert_with(|| serde_json::Value::String(value.to_string()));
+        }
+    }
+    Ok(body)
+}
+
+/// 读取文件开头并识别类型，格式不支持时返回错误
+#[cfg(feature = "stream_upload")]
+async fn read_head<R: AsyncRead + Unpin>(reader: &mut R) -> Result<Vec<u8>, VxwkError> {
+    let mut head = Vec::with_capacity(SNIFF_LEN);
+    reader.take(SNIFF_LEN as u64).read_to_end(&mut head).await?;
+    let mut validator = Validator::new();
+    check_file(&mut validator, &head);
+    validator.finish()?;
+    Ok(head)
+}
+
+impl VxwkAPI {
+    /// 获取活码文件列表
+    #[deprecated(note = "use `live_code_files().list()` instead")]
+    pub async fn live_code_file_url_list(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let result = self.get("/api/v1/user/livecode/file/list", opt).await?;
+        Ok(result.json().await?)
+    }
+    /// 获取活码文件url
+    #[deprecated(note = "use `live_code_files().get()` instead")]
+    pub async fn live_code_file_url(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self.get("/api/v1/user/livecode/file", query_params).await?;
+        Ok(result.json().await?)
+    }
+    /// 上传文件，发送前检查文件大小并根据文件头识别类型，不支持的格式直接返回错误
+    ///
+    /// `opt` 中的 `maxSize` 设置大小上限（默认 [`DEFAULT_MAX_UPLOAD_SIZE`](crate::DEFAULT_MAX_UPLOAD_SIZE)），
+    /// `contentType` 为 `auto` 时附带识别出的类型，其余字段一并放入请求体
+    #[deprecated(note = "use `live_code_files().create_with()` instead")]
+    pub async fn live_code_file_upload(
+        &self,
+        file: Vec<u8>,
+        name: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let body = legacy_upload_body(file, name, opt)?;
+        let result = self.post("/api/v1/user/livecode/file/update", &body).await?;
+        Ok(result)
+    }
+
+    /// 修改活码名称
+    #[deprecated(note = "use `live_code_files().update()` instead")]
+    pub async fn live_code_file_name_update(
+        &self,
+        id: &str,
+        name: &str,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let req = UpdateLiveCodeNameReq {
+            fid: id.to_string(),
+            name: name.to_owned(),
+        };
+        let result = self
+            .post("/api/v1/user/livecode/file/name/update", &req)
+            .await?;
+        Ok(result)
+    }
+
+    /// 删除活码文件
+    #[deprecated(note = "use `live_code_files().delete()` instead")]
+    pub async fn live_code_file_delete(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self
+            .post("/api/v1/user/livecode/file/delete", &query_params)
+            .await?;
+        Ok(result)
+    }
+
+    /// 活码文件接口
+    pub fn live_code_files(&self) -> LiveCodeFiles<'_> {
+        LiveCodeFiles { api: self }
+    }
+}
+
+/// 活码文件接口，通过 [`VxwkAPI::live_code_files`] 获取
+#[derive(Clone, Copy)]
+pub struct LiveCodeFiles<'a> {
+    api: &'a VxwkAPI,
+}
+
+impl<'a> LiveCodeFiles<'a> {
+    /// 活码文件列表，按类型化的筛选条件查询
+    pub async fn list(
+        &self,
+        filter: &LiveCodeFileListFilter,
+    ) -> Result<ApiResponse<Page<LiveCodeFile>>, VxwkError> {
+        resource::list::<LiveCodeFileResource>(self.api, filter).await
+    }
+
+    /// 按筛选条件逐页拉取全部活码文件，返回按需请求的流
+    ///
+    /// `filter` 中的分页字段会被 `opt` 覆盖
+    pub fn stream(
+        self,
+        filter: LiveCodeFileListFilter,
+        opt: PageOptions,
+    ) -> ListStream<'a, LiveCodeFile> {
+        resource::stream::<LiveCodeFileResource>(self.api, filter, opt)
+    }
+
+    /// 获取某个活码关联的文件
+    pub async fn by_live_code(
+        &self,
+        live_code_id: &str,
+    ) -> Result<ApiResponse<Page<LiveCodeFile>>, VxwkError> {
+        let filter = LiveCodeFileListFilter::new().with_live_code(live_code_id);
+        self.list(&filter).await
+    }
+
+    /// 活码文件详情，包含文件的访问地址
+    pub async fn get(&self, id: &str) -> Result<ApiResponse<LiveCodeFile>, VxwkError> {
+        resource::get::<LiveCodeFileResource>(self.api, id).await
+    }
+
+    /// 上传文件，返回新文件的 id，使用默认的 [`UploadOptions`]
+    pub async fn create(
+        &self,
+        file: Vec<u8>,
+        name: &str,
+    ) -> Result<ApiResponse<UploadedFile>, VxwkError> {
+        self.create_with(file, name, UploadOptions::default()).await
+    }
+
+    /// 上传文件，超过 `opt.max_size` 或无法识别的格式不会发出请求
+    pub async fn create_with(
+        &self,
+        file: Vec<u8>,
+        name: &str,
+        opt: UploadOptions,
+    ) -> Result<ApiResponse<UploadedFile>, VxwkError> {
+        let req = CreateLiveCodeFile::new(name, file).with_options(opt);
+        resource::create::<LiveCodeFileResource>(self.api, &req).await
+    }
+
+    /// 从本地路径流式上传文件，边读取边编码，文件超过 `opt.max_size` 或格式不支持时不会发出请求
+    #[cfg(feature = "stream_upload")]
+    pub async fn upload_path(
+        &self,
+        path: impl AsRef<Path>,
+        name: &str,
+        opt: UploadOptions,
+    ) -> Result<ApiResponse<UploadedFile>, VxwkError> {
+        let path = path.as_ref();
+        if tokio::fs::metadata(path).await?.len() > opt.max_size {
+            return Err(too_large(opt.max_size));
+        }
+        read_head(&mut tokio::fs::File::open(path).await?).await?;
+        self.api
+            .upload_stream(name, opt, || Ok(LazyFile::new(path.to_path_buf())))
+            .await
+    }
+
+    /// 从 `reader` 流式上传文件，读取超过 `opt.max_size` 时中止上传
+    ///
+    /// 发出请求前先读取文件开头识别类型，格式不支持时不会发出请求。
+    /// 数据只能读取一次，节点故障时不会切换到其它节点重试
+    #[cfg(feature = "stream_upload")]
+    pub async fn upload_reader<R>(
+        &self,
+        mut reader: R,
+        name: &str,
+        opt: UploadOptions,
+    ) -> Result<ApiResponse<UploadedFile>, VxwkError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let head = read_head(&mut reader).await?;
+        let reader = Mutex::new(Some(io::Cursor::new(head).chain(reader)));
+        self.api
+            .upload_stream(name, opt, || {
+                reader.lock().ok().and_then(|mut r| r.take()).ok_or_else(|| {
+                    io::Error::other("upload data cannot be replayed on another node")
+                })
+            })
+            .await
+    }
+
+    /// 修改文件名称
+    pub async fn update(&self, id: &str, name: &str) -> Result<ApiResponse<()>, VxwkError> {
+        let req = UpdateLiveCodeFile::new(name);
+        resource::update::<LiveCodeFileResource>(self.api, id, &req).await
+    }
+
+    /// 删除活码文件
+    pub async fn delete(&self, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+        resource::delete::<LiveCodeFileResource>(self.api, id).await
+    }
+
+    /// 批量创建活码文件，按输入顺序返回每一项的结果
+    pub async fn batch_create(
+        &self,
+        reqs: &[CreateLiveCodeFile],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<UploadedFile>, VxwkError>> {
+        resource::batch_create::<LiveCodeFileResource>(self.api, reqs, opt).await
+    }
+
+    /// 批量更新活码文件，`items` 为 `(id, 更新请求)`
+    pub async fn batch_update<S: AsRef<str>>(
+        &self,
+        items: &[(S, UpdateLiveCodeFile)],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        resource::batch_update::<LiveCodeFileResource, S>(self.api, items, opt).await
+    }
+
+    /// 批量删除活码文件
+    pub async fn batch_delete<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        resource::batch_delete::<LiveCodeFileResource, S>(self.api, ids, opt).await
+    }
+
+    /// 批量获取活码文件详情
+    pub async fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<LiveCodeFile>, VxwkError>> {
+        resource::get_many::<LiveCodeFileResource, S>(self.api, ids, opt).await
+    }
+}
@@ -0,0 +1,173 @@
+//! 抖音卡片模型
+use crate::validate::{Validate, Validator};
+use crate::VxwkError;
+use serde::{Deserialize, Serialize};
+use super::query::{ListFilter, ListQuery};
+
+/// 抖音卡片
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DyCard {
+    pub id: String,
+    pub title: String,
+    pub describe: String,
+    /// 卡片封面图地址
+    pub img: String,
+    /// 点击卡片跳转的地址
+    pub link: String,
+    pub create_at: i64,
+    pub update_at: i64,
+    /// SDK 暂未建模的字段
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 创建抖音卡片的请求
+/// ```
+/// use vxwk_rs_sdk::{CreateDyCard, Validate};
+///
+/// let req = CreateDyCard::new("新品发布", "https://example.com/item")
+///     .with_img("https://example.com/cover.png");
+/// assert!(req.validate().is_ok());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDyCard {
+    pub title: String,
+    pub link: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub describe: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub img: Option<String>,
+}
+
+impl CreateDyCard {
+    pub fn new(title: &str, link: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            link: link.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_describe(mut self, describe: &str) -> Self {
+        self.describe = Some(describe.to_string());
+        self
+    }
+
+    pub fn with_img(mut self, img: &str) -> Self {
+        self.img = Some(img.to_string());
+        self
+    }
+}
+
+impl Validate for CreateDyCard {
+    fn validate(&self) -> Result<(), VxwkError> {
+        let mut validator = Validator::new();
+        validator.required("title", &self.title);
+        validator.url("link", &self.link);
+        if let Some(img) = &self.img {
+            validator.url("img", img);
+        }
+        validator.finish()
+    }
+}
+
+/// 更新抖音卡片的请求，只会发送设置过的字段
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDyCard {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub describe: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub img: Option<String>,
+}
+
+impl UpdateDyCard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn with_link(mut self, link: &str) -> Self {
+        self.link = Some(link.to_string());
+        self
+    }
+
+    pub fn with_describe(mut self, describe: &str) -> Self {
+        self.describe = Some(describe.to_string());
+        self
+    }
+
+    pub fn with_img(mut self, img: &str) -> Self {
+        self.img = Some(img.to_string());
+        self
+    }
+}
+
+impl Validate for UpdateDyCard {
+    fn validate(&self) -> Result<(), VxwkError> {
+        let mut validator = Validator::new();
+        if let Some(title) = &self.title {
+            validator.required("title", title);
+        }
+        if let Some(link) = &self.link {
+            validator.url("link", link);
+        }
+        if let Some(img) = &self.img {
+            validator.url("img", img);
+        }
+        validator.finish()
+    }
+}
+
+/// 抖音卡片列表的筛选条件
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DyCardListFilter {
+    #[serde(flatten)]
+    pub query: ListQuery,
+}
+
+impl DyCardListFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ListFilter for DyCardListFilter {
+    fn list_query(&self) -> &ListQuery {
+        &self.query
+    }
+
+    fn list_query_mut(&mut self) -> &mut ListQuery {
+        &mut self.query
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CreateDyCard, UpdateDyCard};
+    use crate::validate::Validate;
+    use crate::VxwkError;
+
+    #[test]
+    fn test_error_points_at_field() {
+        let err = CreateDyCard::new("", "example.com").validate().unwrap_err();
+        match err {
+            VxwkError::Validation(errors) => {
+                let fields: Vec<_> = errors.iter().map(|e| e.field.as_str()).collect();
+                assert_eq!(fields, ["title", "link"]);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(UpdateDyCard::new().validate().is_ok());
+    }
+}
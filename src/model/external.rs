@@ -0,0 +1,396 @@
+//! 外链模型
+//!
+//! 字段约束与 `external_url_create` 文档一致：
+//! - `domainID` 为 UUID
+//! - `title` 最长 20，`describe`、`tips`、`hash` 最长 100
+//! - `startAt`、`stopAt` 为秒级时间戳，0 表示不限制
+use crate::validate::{Validate, Validator};
+use crate::VxwkError;
+use serde::{Deserialize, Serialize};
+use super::query::{ListFilter, ListQuery};
+
+/// `title` 最大长度
+pub const TITLE_MAX_LEN: usize = 20;
+/// `describe` 最大长度
+pub const DESCRIBE_MAX_LEN: usize = 100;
+/// `tips` 最大长度
+pub const TIPS_MAX_LEN: usize = 100;
+/// `hash` 最大长度
+pub const HASH_MAX_LEN: usize = 100;
+
+/// 外链展示样式
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Style(String);
+
+impl Style {
+    pub fn new(name: &str) -> Self {
+        Self(name.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// 外链
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ExternalLink {
+    pub id: String,
+    #[serde(rename = "domainID")]
+    pub domain_id: String,
+    pub title: String,
+    pub describe: String,
+    pub tips: Option<String>,
+    pub img: Option<String>,
+    /// 外链类型，接口中的整数 `type`，取值见接口文档
+    #[serde(rename = "type")]
+    pub link_type: i64,
+    pub start_at: i64,
+    pub stop_at: i64,
+    /// 跳转的目标地址
+    pub link: Vec<String>,
+    pub hash: Option<String>,
+    pub test_mode: bool,
+    pub style: Style,
+    /// SDK 暂未建模的字段
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 创建外链的请求
+/// ```
+/// use vxwk_rs_sdk::{CreateExternalLink, Style};
+///
+/// let req = CreateExternalLink::new("8f1d2c3e-0000-4000-8000-000000000000", "活动页", 1)
+///     .with_describe("春季活动")
+///     .add_link("https://example.com/landing")
+///     .with_style(Style::new("default"));
+/// assert_eq!(req.link.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateExternalLink {
+    #[serde(rename = "domainID")]
+    pub domain_id: String,
+    pub title: String,
+    pub describe: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tips: Option<String>,
+    pub img: Option<String>,
+    /// 外链类型，接口中的整数 `type`，取值见接口文档
+    #[serde(rename = "type")]
+    pub link_type: i64,
+    pub start_at: i64,
+    pub stop_at: i64,
+    pub link: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    pub test_mode: bool,
+    pub style: Style,
+}
+
+impl CreateExternalLink {
+    pub fn new(domain_id: &str, title: &str, link_type: i64) -> Self {
+        Self {
+            domain_id: domain_id.to_string(),
+            title: title.to_string(),
+            link_type,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_describe(mut self, describe: &str) -> Self {
+        self.describe = describe.to_string();
+        self
+    }
+
+    pub fn with_tips(mut self, tips: &str) -> Self {
+        self.tips = Some(tips.to_string());
+        self
+    }
+
+    pub fn with_img(mut self, img: &str) -> Self {
+        self.img = Some(img.to_string());
+        self
+    }
+
+    /// 生效时间段，秒级时间戳，0 表示不限制
+    pub fn with_period(mut self, start_at: i64, stop_at: i64) -> Self {
+        self.start_at = start_at;
+        self.stop_at = stop_at;
+        self
+    }
+
+    pub fn add_link(mut self, link: &str) -> Self {
+        self.link.push(link.to_string());
+        self
+    }
+
+    pub fn with_hash(mut self, hash: &str) -> Self {
+        self.hash = Some(hash.to_string());
+        self
+    }
+
+    pub fn with_test_mode(mut self, test_mode: bool) -> Self {
+        self.test_mode = test_mode;
+        self
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl Validate for CreateExternalLink {
+    fn validate(&self) -> Result<(), VxwkError> {
+        let mut validator = Validator::new();
+        validator.uuid("domainID", &self.domain_id);
+        validator.required("title", &self.title);
+        validator.max_chars("title", &self.title, TITLE_MAX_LEN);
+        validator.max_chars("describe", &self.describe, DESCRIBE_MAX_LEN);
+        if let Some(tips) = &self.tips {
+            validator.max_chars("tips", tips, TIPS_MAX_LEN);
+        }
+        if let Some(img) = &self.img {
+            validator.url("img", img);
+        }
+        validator.period(self.start_at, self.stop_at);
+        for link in &self.link {
+            validator.url("link", link);
+        }
+        if let Some(hash) = &self.hash {
+            validator.max_chars("hash", hash, HASH_MAX_LEN);
+        }
+        validator.finish()
+    }
+}
+
+/// 更新外链的请求，只会发送设置过的字段
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateExternalLink {
+    #[serde(rename = "domainID", skip_serializing_if = "Option::is_none")]
+    pub domain_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub describe: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tips: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub img: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub link_type: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_at: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_mode: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<Style>,
+}
+
+impl UpdateExternalLink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_domain_id(mut self, domain_id: &str) -> Self {
+        self.domain_id = Some(domain_id.to_string());
+        self
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn with_describe(mut self, describe: &str) -> Self {
+        self.describe = Some(describe.to_string());
+        self
+    }
+
+    pub fn with_tips(mut self, tips: &str) -> Self {
+        self.tips = Some(tips.to_string());
+        self
+    }
+
+    pub fn with_img(mut self, img: &str) -> Self {
+        self.img = Some(img.to_string());
+        self
+    }
+
+    pub fn with_type(mut self, link_type: i64) -> Self {
+        self.link_type = Some(link_type);
+        self
+    }
+
+    /// 生效时间段，秒级时间戳，0 表示不限制
+    pub fn with_period(mut self, start_at: i64, stop_at: i64) -> Self {
+        self.start_at = Some(start_at);
+        self.stop_at = Some(stop_at);
+        self
+    }
+
+    pub fn with_links(mut self, link: Vec<String>) -> Self {
+        self.link = Some(link);
+        self
+    }
+
+    pub fn with_hash(mut self, hash: &str) -> Self {
+        self.hash = Some(hash.to_string());
+        self
+    }
+
+    pub fn with_test_mode(mut self, test_mode: bool) -> Self {
+        self.test_mode = Some(test_mode);
+        self
+    }
+
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+}
+
+impl Validate for UpdateExternalLink {
+    fn validate(&self) -> Result<(), VxwkError> {
+        let mut validator = Validator::new();
+        if let Some(domain_id) = &self.domain_id {
+            validator.uuid("domainID", domain_id);
+        }
+        if let Some(title) = &self.title {
+            validator.required("title", title);
+            validator.max_chars("title", title, TITLE_MAX_LEN);
+        }
+        if let Some(describe) = &self.describe {
+            validator.max_chars("describe", describe, DESCRIBE_MAX_LEN);
+        }
+        if let Some(tips) = &self.tips {
+            validator.max_chars("tips", tips, TIPS_MAX_LEN);
+        }
+        if let Some(img) = &self.img {
+            validator.url("img", img);
+        }
+        validator.period(self.start_at.unwrap_or(0), self.stop_at.unwrap_or(0));
+        for link in self.link.iter().flatten() {
+            validator.url("link", link);
+        }
+        if let Some(hash) = &self.hash {
+            validator.max_chars("hash", hash, HASH_MAX_LEN);
+        }
+        validator.finish()
+    }
+}
+
+/// 外链列表的筛选条件
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalLinkListFilter {
+    #[serde(flatten)]
+    pub query: ListQuery,
+    /// 只列出某个域名下的外链
+    #[serde(rename = "domainID")]
+    pub domain_id: Option<String>,
+    #[serde(rename = "type")]
+    pub link_type: Option<i64>,
+    /// 只列出测试模式开启或关闭的外链
+    pub test_mode: Option<bool>,
+}
+
+impl ExternalLinkListFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_domain_id(mut self, domain_id: &str) -> Self {
+        self.domain_id = Some(domain_id.to_string());
+        self
+    }
+
+    pub fn with_type(mut self, link_type: i64) -> Self {
+        self.link_type = Some(link_type);
+        self
+    }
+
+    pub fn with_test_mode(mut self, test_mode: bool) -> Self {
+        self.test_mode = Some(test_mode);
+        self
+    }
+}
+
+impl ListFilter for ExternalLinkListFilter {
+    fn list_query(&self) -> &ListQuery {
+        &self.query
+    }
+
+    fn list_query_mut(&mut self) -> &mut ListQuery {
+        &mut self.query
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CreateExternalLink, ExternalLinkListFilter, Style};
+    use crate::model::query::{ListFilter, SortBy, SortOrder};
+    use crate::model::query_pairs;
+    use crate::validate::Validate;
+    use crate::VxwkError;
+    use serde_json::json;
+
+    #[test]
+    fn test_create_body_uses_json_types() {
+        let req = CreateExternalLink::new("uuid", "标题", 1)
+            .add_link("https://a.com")
+            .with_test_mode(true)
+            .with_style(Style::new("dark"));
+        let body = serde_json::to_value(&req).unwrap();
+        assert_eq!(body["type"], 1);
+        assert_eq!(body["testMode"], true);
+        assert_eq!(body["link"], json!(["https://a.com"]));
+        assert_eq!(body["style"], "dark");
+        assert!(body.get("hash").is_none());
+    }
+
+    #[test]
+    fn test_validate_reports_every_field() {
+        let req = CreateExternalLink::new("not-a-uuid", &"标".repeat(21), 1)
+            .with_period(200, 100)
+            .add_link("example.com");
+        match req.validate() {
+            Err(VxwkError::Validation(errors)) => {
+                let fields: Vec<_> = errors.iter().map(|e| e.field.as_str()).collect();
+                assert_eq!(fields, ["domainID", "title", "startAt", "link"]);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_list_filter_pairs() {
+        let filter = ExternalLinkListFilter::new()
+            .with_type(2)
+            .with_test_mode(false)
+            .with_keyword("活动")
+            .with_sort(SortBy::CreateAt, SortOrder::Desc);
+        let mut pairs = query_pairs(&filter).unwrap();
+        pairs.sort();
+        let expected = [
+            ("keyword", "活动"),
+            ("order", "desc"),
+            ("sort", "createAt"),
+            ("testMode", "false"),
+            ("type", "2"),
+        ]
+        .map(|(k, v)| (k.to_string(), v.to_string()));
+        assert_eq!(pairs, expected);
+    }
+}
@@ -0,0 +1,157 @@
+//! 活码模型
+use crate::validate::{Validate, Validator};
+use crate::VxwkError;
+use serde::{Deserialize, Serialize};
+use super::query::{ListFilter, ListQuery};
+use super::livecodefile::LiveCodeFile;
+
+/// 活码
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LiveCode {
+    pub id: String,
+    pub name: String,
+    pub describe: String,
+    /// 活码当前指向的文件
+    #[serde(rename = "fid")]
+    pub file_id: Option<String>,
+    /// 详情接口返回的关联文件，列表接口中通常为空
+    pub files: Vec<LiveCodeFile>,
+    pub create_at: i64,
+    pub update_at: i64,
+    /// SDK 暂未建模的字段
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl LiveCode {
+    /// 活码当前指向的文件
+    pub fn current_file(&self) -> Option<&LiveCodeFile> {
+        let file_id = self.file_id.as_deref()?;
+        self.files.iter().find(|file| file.id == file_id)
+    }
+}
+
+/// 创建活码的请求
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateLiveCode {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub describe: Option<String>,
+    /// 活码指向的文件
+    #[serde(rename = "fid", skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<String>,
+}
+
+impl CreateLiveCode {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_describe(mut self, describe: &str) -> Self {
+        self.describe = Some(describe.to_string());
+        self
+    }
+
+    pub fn with_file(mut self, file_id: &str) -> Self {
+        self.file_id = Some(file_id.to_string());
+        self
+    }
+}
+
+impl Validate for CreateLiveCode {
+    fn validate(&self) -> Result<(), VxwkError> {
+        let mut validator = Validator::new();
+        validator.required("name", &self.name);
+        validator.finish()
+    }
+}
+
+/// 更新活码的请求，只会发送设置过的字段
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateLiveCode {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub describe: Option<String>,
+    #[serde(rename = "fid", skip_serializing_if = "Option::is_none")]
+    pub file_id: Option<String>,
+}
+
+impl UpdateLiveCode {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn with_describe(mut self, describe: &str) -> Self {
+        self.describe = Some(describe.to_string());
+        self
+    }
+
+    /// 将活码切换到另一个文件
+    pub fn with_file(mut self, file_id: &str) -> Self {
+        self.file_id = Some(file_id.to_string());
+        self
+    }
+}
+
+impl Validate for UpdateLiveCode {
+    fn validate(&self) -> Result<(), VxwkError> {
+        let mut validator = Validator::new();
+        if let Some(name) = &self.name {
+            validator.required("name", name);
+        }
+        if let Some(file_id) = &self.file_id {
+            validator.required("fid", file_id);
+        }
+        validator.finish()
+    }
+}
+
+/// 活码列表的筛选条件
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LiveCodeListFilter {
+    #[serde(flatten)]
+    pub query: ListQuery,
+}
+
+impl LiveCodeListFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl ListFilter for LiveCodeListFilter {
+    fn list_query(&self) -> &ListQuery {
+        &self.query
+    }
+
+    fn list_query_mut(&mut self) -> &mut ListQuery {
+        &mut self.query
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::LiveCode;
+    use serde_json::json;
+
+    #[test]
+    fn test_current_file() {
+        let code: LiveCode = serde_json::from_value(json!({
+            "id": "c1",
+            "fid": "f2",
+            "files": [{"id": "f1", "liveCodeID": "c1"}, {"id": "f2", "liveCodeID": "c1"}]
+        }))
+        .unwrap();
+        assert_eq!(code.current_file().unwrap().id, "f2");
+    }
+}
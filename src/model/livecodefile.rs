@@ -0,0 +1,401 @@
+//! 活码文件模型
+use crate::validate::{Validate, Validator};
+use crate::VxwkError;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use super::query::{ListFilter, ListQuery};
+
+#[cfg(feature = "live_code_file")]
+#[derive(Debug, Serialize)]
+pub(crate) struct UpdateLiveCodeNameReq {
+    pub fid: String,
+    pub name: String,
+}
+
+/// 接口要求文件内容以 `base64:` 前缀的字符串上传
+pub(crate) fn encode_file(file: &[u8]) -> String {
+    format!("base64:{}", STANDARD.encode(file))
+}
+
+/// 识别文件类型需要的字节数
+pub(crate) const SNIFF_LEN: usize = 12;
+
+/// 支持上传的图片格式，根据文件头识别
+/// ```
+/// use vxwk_rs_sdk::FileType;
+///
+/// let file_type = FileType::detect(b"\x89PNG\r\n\x1a\n....");
+/// assert_eq!(file_type, Some(FileType::Png));
+/// assert_eq!(FileType::Png.mime(), "image/png");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileType {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+    Bmp,
+}
+
+impl FileType {
+    /// 根据文件开头的魔数识别类型，无法识别时返回 `None`
+    pub fn detect(data: &[u8]) -> Option<Self> {
+        if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(Self::Png)
+        } else if data.starts_with(b"\xff\xd8\xff") {
+            Some(Self::Jpeg)
+        } else if data.starts_with(b"GIF87a") || data.starts_with(b"GIF89a") {
+            Some(Self::Gif)
+        } else if data.len() >= SNIFF_LEN && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+            Some(Self::Webp)
+        } else if data.starts_with(b"BM") {
+            Some(Self::Bmp)
+        } else {
+            None
+        }
+    }
+
+    pub fn mime(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Gif => "image/gif",
+            Self::Webp => "image/webp",
+            Self::Bmp => "image/bmp",
+        }
+    }
+}
+
+/// 默认的文件大小上限，10 MiB
+pub const DEFAULT_MAX_UPLOAD_SIZE: u64 = 10 * 1024 * 1024;
+
+/// 上传选项
+/// ```
+/// use vxwk_rs_sdk::UploadOptions;
+///
+/// let opt = UploadOptions::new()
+///     .with_max_size(2 * 1024 * 1024)
+///     .with_content_type(true);
+/// assert_eq!(opt.max_size, 2 * 1024 * 1024);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadOptions {
+    /// 文件大小上限，单位字节，超出时中止上传
+    pub max_size: u64,
+    /// 在请求中附带识别出的 `contentType`，服务端不支持该字段时保持关闭
+    pub content_type: bool,
+}
+
+impl Default for UploadOptions {
+    fn default() -> Self {
+        Self {
+            max_size: DEFAULT_MAX_UPLOAD_SIZE,
+            content_type: false,
+        }
+    }
+}
+
+impl UploadOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    pub fn with_content_type(mut self, content_type: bool) -> Self {
+        self.content_type = content_type;
+        self
+    }
+}
+
+fn too_large_message(max_size: u64) -> String {
+    format!("must not exceed {} bytes", max_size)
+}
+
+#[cfg(feature = "stream_upload")]
+pub(crate) fn too_large(max_size: u64) -> VxwkError {
+    VxwkError::Validation(vec![crate::validate::FieldError {
+        field: "file".to_string(),
+        message: too_large_message(max_size),
+    }])
+}
+
+/// 校验文件内容并识别类型，`data` 只需包含文件开头的 [`SNIFF_LEN`] 字节
+pub(crate) fn check_file(validator: &mut Validator, data: &[u8]) -> Option<FileType> {
+    if data.is_empty() {
+        validator.error("file", "is empty");
+        return None;
+    }
+    let file_type = FileType::detect(data);
+    if file_type.is_none() {
+        validator.error("file", "unsupported file type, expect png, jpeg, gif, webp or bmp");
+    }
+    file_type
+}
+
+/// 活码文件
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct LiveCodeFile {
+    pub id: String,
+    /// 所属活码，未关联时为空
+    #[serde(rename = "liveCodeID")]
+    pub live_code_id: Option<String>,
+    pub name: String,
+    /// 文件访问地址
+    pub url: String,
+    pub create_at: i64,
+    /// SDK 暂未建模的字段
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 上传活码文件的结果
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct UploadedFile {
+    /// 新文件的 id
+    pub id: String,
+}
+
+impl<'de> Deserialize<'de> for UploadedFile {
+    /// 接口可能直接返回 id，也可能返回包含 `id`/`fid` 的对象
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Id(String),
+            Object {
+                #[serde(alias = "fid")]
+                id: String,
+            },
+        }
+        let id = match Raw::deserialize(deserializer)? {
+            Raw::Id(id) | Raw::Object { id } => id,
+        };
+        Ok(Self { id })
+    }
+}
+
+/// 活码文件列表的筛选条件
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LiveCodeFileListFilter {
+    #[serde(flatten)]
+    pub query: ListQuery,
+    /// 只列出某个活码的文件
+    #[serde(rename = "liveCodeID")]
+    pub live_code_id: Option<String>,
+}
+
+impl LiveCodeFileListFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_live_code(mut self, live_code_id: &str) -> Self {
+        self.live_code_id = Some(live_code_id.to_string());
+        self
+    }
+}
+
+impl ListFilter for LiveCodeFileListFilter {
+    fn list_query(&self) -> &ListQuery {
+        &self.query
+    }
+
+    fn list_query_mut(&mut self) -> &mut ListQuery {
+        &mut self.query
+    }
+}
+
+/// 上传活码文件的请求
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateLiveCodeFile {
+    pub name: String,
+    /// 文件内容，序列化时编码为 base64
+    pub file: Vec<u8>,
+    /// 文件的 MIME 类型，为空时不发送
+    pub content_type: Option<String>,
+    /// 文件大小上限，只用于校验，不会发送
+    pub max_size: u64,
+}
+
+impl Default for CreateLiveCodeFile {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            file: Vec::new(),
+            content_type: None,
+            max_size: DEFAULT_MAX_UPLOAD_SIZE,
+        }
+    }
+}
+
+impl CreateLiveCodeFile {
+    pub fn new(name: &str, file: Vec<u8>) -> Self {
+        Self {
+            name: name.to_string(),
+            file,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_content_type(mut self, content_type: &str) -> Self {
+        self.content_type = Some(content_type.to_string());
+        self
+    }
+
+    /// 按文件头识别出的类型设置 `content_type`，无法识别时保持不变
+    pub fn with_detected_content_type(mut self) -> Self {
+        if let Some(file_type) = FileType::detect(&self.file) {
+            self.content_type = Some(file_type.mime().to_string());
+        }
+        self
+    }
+
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// 按上传选项设置大小上限，开启 `content_type` 时附带识别出的类型
+    pub fn with_options(self, opt: UploadOptions) -> Self {
+        let req = self.with_max_size(opt.max_size);
+        if opt.content_type {
+            req.with_detected_content_type()
+        } else {
+            req
+        }
+    }
+}
+
+impl Serialize for CreateLiveCodeFile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = if self.content_type.is_some() { 3 } else { 2 };
+        let mut state = serializer.serialize_struct("CreateLiveCodeFile", len)?;
+        state.serialize_field("file", &encode_file(&self.file))?;
+        state.serialize_field("name", &self.name)?;
+        if let Some(content_type) = &self.content_type {
+            state.serialize_field("contentType", content_type)?;
+        }
+        state.end()
+    }
+}
+
+impl Validate for CreateLiveCodeFile {
+    fn validate(&self) -> Result<(), VxwkError> {
+        let mut validator = Validator::new();
+        validator.required("name", &self.name);
+        if self.file.len() as u64 > self.max_size {
+            validator.error("file", too_large_message(self.max_size));
+        }
+        check_file(&mut validator, &self.file);
+        validator.finish()
+    }
+}
+
+/// 修改活码文件的请求，目前只能修改名称
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateLiveCodeFile {
+    pub name: String,
+}
+
+impl UpdateLiveCodeFile {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+}
+
+impl Validate for UpdateLiveCodeFile {
+    fn validate(&self) -> Result<(), VxwkError> {
+        let mut validator = Validator::new();
+        validator.required("name", &self.name);
+        validator.finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        CreateLiveCodeFile, FileType, UploadOptions, UploadedFile, DEFAULT_MAX_UPLOAD_SIZE,
+    };
+    use crate::validate::Validate;
+    use serde_json::json;
+
+    #[test]
+    fn test_create_file_body() {
+        let req = CreateLiveCodeFile::new("a.png", b"png".to_vec());
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            json!({"file": "base64:cG5n", "name": "a.png"})
+        );
+        let req = req.with_content_type("image/png");
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            json!({"file": "base64:cG5n", "name": "a.png", "contentType": "image/png"})
+        );
+        assert!(CreateLiveCodeFile::new("a.png", Vec::new())
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_validate_file_size() {
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png.resize(DEFAULT_MAX_UPLOAD_SIZE as usize + 1, 0);
+        let req = CreateLiveCodeFile::new("a.png", png);
+        assert!(req.validate().is_err());
+
+        let opt = UploadOptions::new()
+            .with_max_size(DEFAULT_MAX_UPLOAD_SIZE * 2)
+            .with_content_type(true);
+        let req = req.with_options(opt);
+        assert!(req.validate().is_ok());
+        assert_eq!(req.content_type.as_deref(), Some("image/png"));
+    }
+
+    #[test]
+    fn test_detect_file_type() {
+        let cases: [(&[u8], Option<FileType>); 6] = [
+            (b"\x89PNG\r\n\x1a\n\0\0", Some(FileType::Png)),
+            (b"\xff\xd8\xff\xe0", Some(FileType::Jpeg)),
+            (b"GIF89a", Some(FileType::Gif)),
+            (b"RIFF\0\0\0\0WEBPVP8 ", Some(FileType::Webp)),
+            (b"RIFF\0\0\0\0WAVE", None),
+            (b"%PDF-1.7", None),
+        ];
+        for (data, expected) in cases {
+            assert_eq!(FileType::detect(data), expected);
+        }
+        assert!(CreateLiveCodeFile::new("a.gif", b"GIF87a".to_vec())
+            .validate()
+            .is_ok());
+        assert!(CreateLiveCodeFile::new("a.pdf", b"%PDF-1.7".to_vec())
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_uploaded_file_id() {
+        for value in [
+            json!("f1"),
+            json!({"id": "f1"}),
+            json!({"fid": "f1", "name": "a.png"}),
+        ] {
+            let file: UploadedFile = serde_json::from_value(value).unwrap();
+            assert_eq!(file.id, "f1");
+        }
+    }
+}
@@ -0,0 +1,289 @@
+//! 接口使用的数据模型
+use crate::VxwkError;
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
+use serde::de::{self, DeserializeOwned};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::time::Duration;
+
+#[cfg(feature = "dy_card")]
+pub mod dycard;
+#[cfg(feature = "external")]
+pub mod external;
+#[cfg(feature = "live_code")]
+pub mod livecode;
+// 活码详情中包含文件模型，两个 feature 任一开启时都需要
+#[cfg(any(feature = "live_code", feature = "live_code_file"))]
+pub mod livecodefile;
+pub mod query;
+#[cfg(feature = "short_link")]
+pub mod shortlink;
+#[cfg(feature = "wx_card")]
+pub mod wxcard;
+
+/// 列表接口返回的一页数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Page<T> {
+    #[serde(alias = "items", alias = "records")]
+    pub list: Vec<T>,
+    #[serde(alias = "count")]
+    pub total: u64,
+}
+
+impl<T> Default for Page<T> {
+    fn default() -> Self {
+        Self {
+            list: Vec::new(),
+            total: 0,
+        }
+    }
+}
+
+/// 将 `id` 合并进请求体，其它字段保留原本的 JSON 类型，用于更新类接口
+pub(crate) fn with_id<T>(id: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+where
+    T: Serialize + ?Sized,
+{
+    let value =
+        serde_json::to_value(body).map_err(|err| VxwkError::InvalidRequest(err.to_string()))?;
+    let mut map = match value {
+        serde_json::Value::Object(map) => map,
+        serde_json::Value::Null => serde_json::Map::new(),
+        other => {
+            return Err(VxwkError::InvalidRequest(format!(
+                "request body must be an object, got `{}`",
+                other
+            )))
+        }
+    };
+    map.insert("id".to_string(), serde_json::Value::String(id.to_string()));
+    Ok(serde_json::Value::Object(map))
+}
+
+/// 接口统一的响应结构
+#[derive(Debug, Clone)]
+pub struct ApiResponse<T> {
+    /// 业务状态码
+    pub code: i64,
+    pub msg: String,
+    pub data: T,
+    /// HTTP 层面的响应信息
+    pub meta: ResponseMeta,
+}
+
+/// 单次请求的元信息
+#[derive(Debug, Clone)]
+pub struct ResponseMeta {
+    /// HTTP 状态码
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    /// 请求签名使用的随机数 `xn`
+    pub nonce: String,
+    /// 处理本次请求的节点
+    pub node: String,
+    /// 从发出请求到收到响应头的耗时
+    pub latency: Duration,
+}
+
+/// 表示成功的业务状态码，响应没有 `code` 字段时按 0 处理
+const SUCCESS_CODES: [i64; 2] = [0, 200];
+
+#[derive(Deserialize)]
+struct Envelope {
+    #[serde(default, deserialize_with = "code_from_any")]
+    code: i64,
+    #[serde(default, alias = "message")]
+    msg: String,
+}
+
+/// 状态码可能是数字，也可能是数字字符串，其它类型视为无效响应
+fn code_from_any<'de, D>(deserializer: D) -> Result<i64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Number(code) => code
+            .as_i64()
+            .ok_or_else(|| de::Error::custom(format!("invalid code `{}`", code))),
+        serde_json::Value::String(code) => code
+            .parse()
+            .map_err(|_| de::Error::custom(format!("invalid code `{}`", code))),
+        other => Err(de::Error::custom(format!("invalid code `{}`", other))),
+    }
+}
+
+impl<T> ApiResponse<T> {
+    /// 只保留 `data`
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    /// 转换 `data`，保留状态码与元信息
+    pub fn map<U, F>(self, f: F) -> ApiResponse<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        ApiResponse {
+            code: self.code,
+            msg: self.msg,
+            data: f(self.data),
+            meta: self.meta,
+        }
+    }
+}
+
+/// 查找或创建的结果
+#[derive(Debug, Clone)]
+pub enum FindOrCreate<T> {
+    /// 已存在的记录，没有发出创建请求
+    Found(T),
+    /// 新创建的记录
+    Created(ApiResponse<T>),
+}
+
+impl<T> FindOrCreate<T> {
+    pub fn is_created(&self) -> bool {
+        matches!(self, FindOrCreate::Created(_))
+    }
+
+    /// 取出记录，不区分是否新建
+    pub fn into_inner(self) -> T {
+        match self {
+            FindOrCreate::Found(item) => item,
+            FindOrCreate::Created(res) => res.data,
+        }
+    }
+}
+
+impl<T> ApiResponse<T>
+where
+    T: DeserializeOwned,
+{
+    /// 解析响应体，响应没有 `data` 字段时按整体解析为 `data`
+    ///
+    /// 业务状态码不是成功时返回 [`VxwkError::Api`]，不再解析 `data`
+    pub(crate) fn decode(
+        mut value: serde_json::Value,
+        meta: ResponseMeta,
+    ) -> Result<Self, VxwkError> {
+        let envelope: Envelope = serde_json::from_value(value.clone())
+            .map_err(|err| VxwkError::InvalidResponse(err.to_string()))?;
+        if !SUCCESS_CODES.contains(&envelope.code) {
+            return Err(VxwkError::Api {
+                code: envelope.code,
+                msg: envelope.msg,
+            });
+        }
+        let data = match value.get_mut("data") {
+            Some(data) => data.take(),
+            None => value,
+        };
+        let data = serde_json::from_value(data)
+            .map_err(|err| VxwkError::InvalidResponse(err.to_string()))?;
+        Ok(Self {
+            code: envelope.code,
+            msg: envelope.msg,
+            data,
+            meta,
+        })
+    }
+}
+
+/// 将类型化的查询条件展开为查询参数，值为 `None` 的字段会被省略
+pub(crate) fn query_pairs<T>(query: &T) -> Result<Vec<(String, String)>, VxwkError>
+where
+    T: Serialize,
+{
+    let value =
+        serde_json::to_value(query).map_err(|err| VxwkError::InvalidRequest(err.to_string()))?;
+    let map = match value {
+        serde_json::Value::Object(map) => map,
+        serde_json::Value::Null => return Ok(Vec::new()),
+        other => {
+            return Err(VxwkError::InvalidRequest(format!(
+                "query must be an object, got `{}`",
+                other
+            )))
+        }
+    };
+    Ok(map
+        .into_iter()
+        .filter_map(|(key, value)| match value {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(value) => Some((key, value)),
+            other => Some((key, other.to_string())),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{query_pairs, with_id, ApiResponse, Page, ResponseMeta};
+    use crate::VxwkError;
+    use reqwest::header::HeaderMap;
+    use reqwest::StatusCode;
+    use serde::de::IgnoredAny;
+    use serde_json::json;
+    use std::time::Duration;
+
+    fn meta() -> ResponseMeta {
+        ResponseMeta {
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+            nonce: "123".into(),
+            node: "https://vxwk.example.com".into(),
+            latency: Duration::from_millis(5),
+        }
+    }
+
+    #[test]
+    fn test_decode_page() {
+        let value = json!({"code": "200", "msg": "ok", "data": {"list": [1, 2], "total": 10}});
+        let res: ApiResponse<Page<u32>> = ApiResponse::decode(value, meta()).unwrap();
+        assert_eq!(res.code, 200);
+        assert_eq!(res.msg, "ok");
+        assert_eq!(res.data.list, [1, 2]);
+        assert_eq!(res.data.total, 10);
+        assert_eq!(res.meta.nonce, "123");
+    }
+
+    #[test]
+    fn test_decode_error_envelope() {
+        let value = json!({"code": 500, "msg": "域名不存在"});
+        let err = ApiResponse::<IgnoredAny>::decode(value, meta()).unwrap_err();
+        assert!(matches!(err, VxwkError::Api { code: 500, ref msg } if msg == "域名不存在"));
+    }
+
+    #[test]
+    fn test_decode_invalid_code() {
+        for value in [json!({"code": null, "msg": "x"}), json!({"code": true, "msg": "x"})] {
+            let err = ApiResponse::<IgnoredAny>::decode(value, meta()).unwrap_err();
+            assert!(matches!(err, VxwkError::InvalidResponse(_)));
+        }
+        // 没有 `code` 字段时按成功处理
+        assert!(ApiResponse::<IgnoredAny>::decode(json!({"data": {}}), meta()).is_ok());
+    }
+
+    #[test]
+    fn test_query_pairs_skip_none() {
+        let pairs = query_pairs(&json!({"page": 2, "keyword": "春", "status": null})).unwrap();
+        assert_eq!(
+            pairs,
+            [
+                ("keyword".to_string(), "春".to_string()),
+                ("page".to_string(), "2".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_id_keeps_json_types() {
+        let body = json!({"type": 2, "testMode": true, "link": ["https://a.com"]});
+        assert_eq!(
+            with_id("42", &body).unwrap(),
+            json!({"id": "42", "type": 2, "testMode": true, "link": ["https://a.com"]})
+        );
+        assert!(with_id("42", &json!([1, 2])).is_err());
+    }
+}
@@ -0,0 +1,170 @@
+//! 列表接口共用的查询条件
+use crate::validate::{Validate, Validator};
+use crate::VxwkError;
+use serde::{Deserialize, Serialize};
+
+/// 排序字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SortBy {
+    /// 按创建时间排序
+    CreateAt,
+    /// 按更新时间排序
+    UpdateAt,
+}
+
+/// 排序方向
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// 所有列表接口都支持的查询条件，值为 `None` 的字段不会出现在查询参数中
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListQuery {
+    pub page: Option<u32>,
+    pub page_size: Option<u32>,
+    /// 模糊搜索
+    pub keyword: Option<String>,
+    /// 创建时间下限，秒级时间戳
+    pub create_start: Option<i64>,
+    /// 创建时间上限，秒级时间戳
+    pub create_end: Option<i64>,
+    pub sort: Option<SortBy>,
+    pub order: Option<SortOrder>,
+}
+
+impl Validate for ListQuery {
+    fn validate(&self) -> Result<(), VxwkError> {
+        let mut validator = Validator::new();
+        if self.page == Some(0) {
+            validator.error("page", "must start from 1");
+        }
+        if self.page_size == Some(0) {
+            validator.error("pageSize", "must be greater than 0");
+        }
+        if let (Some(start), Some(end)) = (self.create_start, self.create_end) {
+            if start > end {
+                validator.error("createStart", "must not be later than `createEnd`");
+            }
+        }
+        validator.finish()
+    }
+}
+
+/// 列表筛选条件，提供通用查询字段的构造方法
+///
+/// ```
+/// # #[cfg(feature = "short_link")]
+/// # fn main() {
+/// use vxwk_rs_sdk::{ListFilter, ShortLinkListFilter, SortBy, SortOrder};
+///
+/// let filter = ShortLinkListFilter::new()
+///     .with_page(1, 50)
+///     .with_keyword("春季")
+///     .with_created_between(1700000000, 1710000000)
+///     .with_sort(SortBy::CreateAt, SortOrder::Desc);
+/// assert_eq!(filter.list_query().page_size, Some(50));
+/// # }
+/// # #[cfg(not(feature = "short_link"))]
+/// # fn main() {}
+/// ```
+pub trait ListFilter: Serialize + Clone + Send + Sync {
+    fn list_query(&self) -> &ListQuery;
+
+    fn list_query_mut(&mut self) -> &mut ListQuery;
+
+    fn with_page(mut self, page: u32, page_size: u32) -> Self
+    where
+        Self: Sized,
+    {
+        let query = self.list_query_mut();
+        query.page = Some(page);
+        query.page_size = Some(page_size);
+        self
+    }
+
+    fn with_keyword(mut self, keyword: &str) -> Self
+    where
+        Self: Sized,
+    {
+        self.list_query_mut().keyword = Some(keyword.to_string());
+        self
+    }
+
+    /// 只列出创建时间在 `[start, end]` 之间的记录，秒级时间戳
+    fn with_created_between(mut self, start: i64, end: i64) -> Self
+    where
+        Self: Sized,
+    {
+        let query = self.list_query_mut();
+        query.create_start = Some(start);
+        query.create_end = Some(end);
+        self
+    }
+
+    fn with_sort(mut self, sort: SortBy, order: SortOrder) -> Self
+    where
+        Self: Sized,
+    {
+        let query = self.list_query_mut();
+        query.sort = Some(sort);
+        query.order = Some(order);
+        self
+    }
+}
+
+impl ListFilter for ListQuery {
+    fn list_query(&self) -> &ListQuery {
+        self
+    }
+
+    fn list_query_mut(&mut self) -> &mut ListQuery {
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ListFilter, ListQuery, SortBy, SortOrder};
+    use crate::model::query_pairs;
+    use crate::validate::Validate;
+
+    #[test]
+    fn test_list_query_pairs() {
+        let query = ListQuery::default()
+            .with_page(2, 50)
+            .with_created_between(100, 200)
+            .with_sort(SortBy::UpdateAt, SortOrder::Asc);
+        let mut pairs = query_pairs(&query).unwrap();
+        pairs.sort();
+        let expected = [
+            ("createEnd", "200"),
+            ("createStart", "100"),
+            ("order", "asc"),
+            ("page", "2"),
+            ("pageSize", "50"),
+            ("sort", "updateAt"),
+        ]
+        .map(|(k, v)| (k.to_string(), v.to_string()));
+        assert_eq!(pairs, expected);
+    }
+
+    #[test]
+    fn test_list_query_validate() {
+        assert!(ListQuery::default().with_page(1, 20).validate().is_ok());
+        let err = ListQuery::default()
+            .with_page(0, 20)
+            .with_created_between(200, 100)
+            .validate()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Invalid fields `page: must start from 1; createStart: must not be later than `createEnd``"
+        );
+    }
+}
@@ -0,0 +1,241 @@
+//! 短链模型
+use crate::validate::{Validate, Validator};
+use crate::VxwkError;
+use serde::{Deserialize, Serialize};
+use super::query::{ListFilter, ListQuery};
+
+/// `title` 最大长度
+pub const TITLE_MAX_LEN: usize = 20;
+/// `describe` 最大长度
+pub const DESCRIBE_MAX_LEN: usize = 100;
+/// `hash` 最大长度
+pub const HASH_MAX_LEN: usize = 100;
+
+/// 短链
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ShortLink {
+    pub id: String,
+    #[serde(rename = "domainID")]
+    pub domain_id: String,
+    /// 跳转的目标地址
+    pub link: String,
+    pub hash: String,
+    pub title: String,
+    pub describe: String,
+    pub create_at: i64,
+    pub update_at: i64,
+    /// SDK 暂未建模的字段
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// 创建短链的请求
+/// ```
+/// use vxwk_rs_sdk::CreateShortLink;
+///
+/// let req = CreateShortLink::new("8f1d2c3e-0a1b-4c2d-8e3f-0123456789ab", "https://example.com/landing")
+///     .with_title("活动落地页")
+///     .with_hash("spring");
+/// assert_eq!(req.hash.as_deref(), Some("spring"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateShortLink {
+    #[serde(rename = "domainID")]
+    pub domain_id: String,
+    pub link: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub describe: Option<String>,
+}
+
+impl CreateShortLink {
+    pub fn new(domain_id: &str, link: &str) -> Self {
+        Self {
+            domain_id: domain_id.to_string(),
+            link: link.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// 自定义短链后缀
+    pub fn with_hash(mut self, hash: &str) -> Self {
+        self.hash = Some(hash.to_string());
+        self
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn with_describe(mut self, describe: &str) -> Self {
+        self.describe = Some(describe.to_string());
+        self
+    }
+
+    /// 是否与已有短链指向同一目标：域名和目标地址相同，设置了 `hash` 时后缀也要相同
+    pub fn matches(&self, link: &ShortLink) -> bool {
+        self.domain_id == link.domain_id
+            && self.link == link.link
+            && self.hash.as_ref().map_or(true, |hash| *hash == link.hash)
+    }
+}
+
+impl Validate for CreateShortLink {
+    fn validate(&self) -> Result<(), VxwkError> {
+        let mut validator = Validator::new();
+        validator.uuid("domainID", &self.domain_id);
+        validator.url("link", &self.link);
+        if let Some(hash) = &self.hash {
+            validator.max_chars("hash", hash, HASH_MAX_LEN);
+        }
+        if let Some(title) = &self.title {
+            validator.max_chars("title", title, TITLE_MAX_LEN);
+        }
+        if let Some(describe) = &self.describe {
+            validator.max_chars("describe", describe, DESCRIBE_MAX_LEN);
+        }
+        validator.finish()
+    }
+}
+
+/// 更新短链的请求，只会发送设置过的字段
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateShortLink {
+    #[serde(rename = "domainID", skip_serializing_if = "Option::is_none")]
+    pub domain_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub describe: Option<String>,
+}
+
+impl UpdateShortLink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_domain_id(mut self, domain_id: &str) -> Self {
+        self.domain_id = Some(domain_id.to_string());
+        self
+    }
+
+    pub fn with_link(mut self, link: &str) -> Self {
+        self.link = Some(link.to_string());
+        self
+    }
+
+    pub fn with_hash(mut self, hash: &str) -> Self {
+        self.hash = Some(hash.to_string());
+        self
+    }
+
+    pub fn with_title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn with_describe(mut self, describe: &str) -> Self {
+        self.describe = Some(describe.to_string());
+        self
+    }
+}
+
+impl Validate for UpdateShortLink {
+    fn validate(&self) -> Result<(), VxwkError> {
+        let mut validator = Validator::new();
+        if let Some(domain_id) = &self.domain_id {
+            validator.uuid("domainID", domain_id);
+        }
+        if let Some(link) = &self.link {
+            validator.url("link", link);
+        }
+        if let Some(hash) = &self.hash {
+            validator.max_chars("hash", hash, HASH_MAX_LEN);
+        }
+        if let Some(title) = &self.title {
+            validator.max_chars("title", title, TITLE_MAX_LEN);
+        }
+        if let Some(describe) = &self.describe {
+            validator.max_chars("describe", describe, DESCRIBE_MAX_LEN);
+        }
+        validator.finish()
+    }
+}
+
+/// 短链列表的筛选条件
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShortLinkListFilter {
+    #[serde(flatten)]
+    pub query: ListQuery,
+    /// 只列出某个域名下的短链
+    #[serde(rename = "domainID")]
+    pub domain_id: Option<String>,
+}
+
+impl ShortLinkListFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_domain_id(mut self, domain_id: &str) -> Self {
+        self.domain_id = Some(domain_id.to_string());
+        self
+    }
+}
+
+impl ListFilter for ShortLinkListFilter {
+    fn list_query(&self) -> &ListQuery {
+        &self.query
+    }
+
+    fn list_query_mut(&mut self) -> &mut ListQuery {
+        &mut self.query
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CreateShortLink, ShortLink, UpdateShortLink};
+    use crate::model::with_id;
+    use serde_json::json;
+
+    #[test]
+    fn test_update_only_sends_set_fields() {
+        let req = UpdateShortLink::new().with_title("新标题");
+        let body = with_id("1", &req).unwrap();
+        assert_eq!(body, json!({"id": "1", "title": "新标题"}));
+    }
+
+    #[test]
+    fn test_keep_unknown_fields() {
+        let link: ShortLink =
+            serde_json::from_value(json!({"id": "1", "link": "https://a.com", "pv": 3})).unwrap();
+        assert_eq!(link.link, "https://a.com");
+        assert_eq!(link.extra["pv"], 3);
+    }
+
+    #[test]
+    fn test_create_matches_existing() {
+        let existing: ShortLink = serde_json::from_value(
+            json!({"id": "1", "domainID": "d", "link": "https://a.com", "hash": "spring"}),
+        )
+        .unwrap();
+        assert!(CreateShortLink::new("d", "https://a.com").matches(&existing));
+        assert!(CreateShortLink::new("d", "https://a.com")
+            .with_hash("spring")
+            .matches(&existing));
+        assert!(!CreateShortLink::new("d", "https://a.com")
+            .with_hash("summer")
+            .matches(&existing));
+        assert!(!CreateShortLink::new("e", "https://a.com").matches(&existing));
+    }
+}
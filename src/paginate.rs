@@ -0,0 +1,395 @@
+//! 列表接口的分页流
+use crate::model::Page;
+use crate::VxwkError;
+use futures::stream::{self, BoxStream, Stream, StreamExt, TryStreamExt};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+/// 默认每页条数
+pub const DEFAULT_PAGE_SIZE: u32 = 20;
+/// 单页请求失败后默认的重试次数
+pub const DEFAULT_PAGE_RETRIES: u32 = 2;
+/// 第一次重试前的等待时间，之后每次翻倍
+const RETRY_BACKOFF: Duration = Duration::from_millis(200);
+
+/// 分页流的选项
+/// ```
+/// use vxwk_rs_sdk::PageOptions;
+///
+/// // 每页 100 条，最多取 1000 条
+/// let opt = PageOptions::new().with_page_size(100).with_limit(1000);
+/// assert_eq!(opt.start_page, 1);
+///
+/// // 读取第一页的总数后，最多同时请求 8 页
+/// let opt = PageOptions::new().with_page_size(100).with_concurrency(8);
+/// assert_eq!(opt.concurrency, 8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageOptions {
+    pub page_size: u32,
+    /// 起始页码，从 1 开始
+    pub start_page: u32,
+    /// 最多返回的条数，`None` 表示取完为止
+    pub limit: Option<usize>,
+    /// 同时请求的页数，大于 1 时根据第一页的 `total` 并发预取剩余页，结果仍按页码顺序返回
+    pub concurrency: usize,
+    /// 单页遇到连接失败、超时或 5xx 时的重试次数
+    pub retries: u32,
+}
+
+impl Default for PageOptions {
+    fn default() -> Self {
+        Self {
+            page_size: DEFAULT_PAGE_SIZE,
+            start_page: 1,
+            limit: None,
+            concurrency: 1,
+            retries: DEFAULT_PAGE_RETRIES,
+        }
+    }
+}
+
+impl PageOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    pub fn with_start_page(mut self, start_page: u32) -> Self {
+        self.start_page = start_page.max(1);
+        self
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+}
+
+/// 按需逐页拉取的列表流，丢弃或停止轮询即可提前结束
+pub struct ListStream<'a, T> {
+    inner: BoxStream<'a, Result<T, VxwkError>>,
+}
+
+impl<'a, T> ListStream<'a, T>
+where
+    T: Send + 'a,
+{
+    /// 按页拉取，`fetch` 接收页码与每页条数
+    ///
+    /// 接口返回 `total` 时，遇到空页或已达到 `total` 才停止，服务端限制了每页条数也能取完；
+    /// 没有 `total` 时遇到空页或不满一页即停止
+    pub(crate) fn new<F, Fut>(opt: PageOptions, fetch: F) -> Self
+    where
+        F: Fn(u32, u32) -> Fut + Send + Sync + 'a,
+        Fut: Future<Output = Result<Page<T>, VxwkError>> + Send + 'a,
+    {
+        let items = if opt.concurrency > 1 {
+            prefetch(opt, Arc::new(fetch))
+        } else {
+            sequential(opt, 0, Arc::new(fetch))
+        };
+        let inner = match opt.limit {
+            Some(limit) => items.take(limit).boxed(),
+            None => items,
+        };
+        Self { inner }
+    }
+
+    /// 拉取全部数据，遇到错误立即返回
+    pub async fn collect_all(self) -> Result<Vec<T>, VxwkError> {
+        self.inner.try_collect().await
+    }
+}
+
+/// 一页接一页地拉取，`fetched` 为此前已取到的条数
+fn sequential<'a, T, F, Fut>(
+    opt: PageOptions,
+    fetched: u64,
+    fetch: Arc<F>,
+) -> BoxStream<'a, Result<T, VxwkError>>
+where
+    T: Send + 'a,
+    F: Fn(u32, u32) -> Fut + Send + Sync + 'a,
+    Fut: Future<Output = Result<Page<T>, VxwkError>> + Send + 'a,
+{
+    let page_size = opt.page_size.max(1);
+    let pages = stream::try_unfold(
+        (Some(opt.start_page.max(1)), fetched),
+        move |(page, fetched)| {
+            let fetch = fetch.clone();
+            async move {
+                let page = match page {
+                    Some(page) => page,
+                    None => return Ok(None),
+                };
+                let result = fetch_page(&*fetch, page, page_size, opt.retries).await?;
+                let fetched = fetched + result.list.len() as u64;
+                let done = is_last_page(&result, page_size, fetched);
+                let next_page = if done { None } else { Some(page + 1) };
+                Ok::<_, VxwkError>(Some((result.list, (next_page, fetched))))
+            }
+        },
+    );
+    pages
+        .map_ok(|list| stream::iter(list.into_iter().map(Ok)))
+        .try_flatten()
+        .boxed()
+}
+
+/// 已取到 `fetched` 条后，`page` 是否为最后一页
+fn is_last_page<T>(page: &Page<T>, page_size: u32, fetched: u64) -> bool {
+    if page.total > 0 {
+        page.list.is_empty() || fetched >= page.total
+    } else {
+        page.list.len() < page_size as usize
+    }
+}
+
+/// 先拉取第一页得到 `total`，再并发请求剩余页，按页码顺序输出
+///
+/// 接口没有返回 `total`，或第一页不满说明服务端限制了每页条数时，退回逐页拉取
+fn prefetch<'a, T, F, Fut>(opt: PageOptions, fetch: Arc<F>) -> BoxStream<'a, Result<T, VxwkError>>
+where
+    T: Send + 'a,
+    F: Fn(u32, u32) -> Fut + Send + Sync + 'a,
+    Fut: Future<Output = Result<Page<T>, VxwkError>> + Send + 'a,
+{
+    let page_size = opt.page_size.max(1);
+    let first_page = opt.start_page.max(1);
+    let first = {
+        let fetch = fetch.clone();
+        async move { fetch_page(&*fetch, first_page, page_size, opt.retries).await }
+    };
+    stream::once(first)
+        .map_ok(move |first| {
+            let fetched = first.list.len() as u64;
+            let full = first.list.len() >= page_size as usize;
+            let last = is_last_page(&first, page_size, fetched);
+            let total = first.total;
+            let head = stream::iter(first.list.into_iter().map(Ok));
+            if last {
+                return head.boxed();
+            }
+            let fetch = fetch.clone();
+            let rest = if total == 0 || !full {
+                sequential(opt.with_start_page(first_page + 1), fetched, fetch)
+            } else {
+                let mut last_page = total.div_ceil(page_size as u64);
+                if let Some(limit) = opt.limit {
+                    let needed = (limit as u64).div_ceil(page_size as u64).max(1);
+                    last_page = last_page.min(first_page as u64 + needed - 1);
+                }
+                let pages = (first_page as u64 + 1)..=last_page;
+                stream::iter(pages)
+                    .map(move |page| {
+                        let fetch = fetch.clone();
+                        async move {
+                            fetch_page(&*fetch, page as u32, page_size, opt.retries)
+                                .await
+                                .map(|result| result.list)
+                        }
+                    })
+                    .buffered(opt.concurrency)
+                    .map_ok(|list| stream::iter(list.into_iter().map(Ok)))
+                    .try_flatten()
+                    .boxed()
+            };
+            head.chain(rest).boxed()
+        })
+        .try_flatten()
+        .boxed()
+}
+
+/// 拉取单页，可重试的错误最多重试 `retries` 次，每次重试前等待的时间翻倍
+async fn fetch_page<T, F, Fut>(
+    fetch: &F,
+    page: u32,
+    page_size: u32,
+    retries: u32,
+) -> Result<Page<T>, VxwkError>
+where
+    F: Fn(u32, u32) -> Fut,
+    Fut: Future<Output = Result<Page<T>, VxwkError>>,
+{
+    let mut attempt = 0;
+    loop {
+        match fetch(page, page_size).await {
+            Err(err) if attempt < retries && retryable(&err) => {
+                attempt += 1;
+                log::warn!(
+                    "vxwk page {} failed, retry {}/{}: {}",
+                    page,
+                    attempt,
+                    retries,
+                    err
+                );
+                futures_timer::Delay::new(RETRY_BACKOFF * 2u32.pow(attempt - 1)).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// 只重试连接失败、超时与 5xx，4xx 或响应格式不对时重试也不会成功
+fn retryable(err: &VxwkError) -> bool {
+    match err {
+        VxwkError::ReqwestError(err) => {
+            err.is_connect()
+                || err.is_timeout()
+                || err.status().is_some_and(|status| status.is_server_error())
+        }
+        _ => false,
+    }
+}
+
+impl<T> Stream for ListStream<'_, T> {
+    type Item = Result<T, VxwkError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ListStream, PageOptions};
+    use crate::model::Page;
+    use crate::VxwkError;
+    use futures::StreamExt;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Mutex;
+
+    fn page(page: u32, page_size: u32, total: u32) -> Page<u32> {
+        let start = (page - 1) * page_size;
+        Page {
+            list: (start..total.min(start + page_size)).collect(),
+            total: total as u64,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_collect_all_pages() {
+        let opt = PageOptions::new().with_page_size(3);
+        let stream = ListStream::new(opt, |p, size| async move { Ok(page(p, size, 10)) });
+        assert_eq!(
+            stream.collect_all().await.unwrap(),
+            (0..10).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_server_caps_page_size() {
+        // 请求每页 3 条，服务端只返回 2 条，仍按 total 取完
+        for concurrency in [1, 4] {
+            let opt = PageOptions::new()
+                .with_page_size(3)
+                .with_concurrency(concurrency);
+            let stream = ListStream::new(opt, |p, _| async move { Ok(page(p, 2, 7)) });
+            assert_eq!(
+                stream.collect_all().await.unwrap(),
+                (0..7).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stop_early() {
+        let calls = AtomicU32::new(0);
+        let opt = PageOptions::new().with_page_size(3);
+        let stream = ListStream::new(opt, |p, size| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async move { Ok(page(p, size, 100)) }
+        });
+        let first: Vec<_> = stream.take(4).collect().await;
+        assert_eq!(first.len(), 4);
+        // 只拉取了前两页
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_keeps_order() {
+        let calls = AtomicU32::new(0);
+        let opt = PageOptions::new().with_page_size(3).with_concurrency(4);
+        let stream = ListStream::new(opt, |p, size| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async move {
+                // 页码越小返回越慢
+                for _ in 0..(10 - p) {
+                    tokio::task::yield_now().await;
+                }
+                Ok(page(p, size, 10))
+            }
+        });
+        assert_eq!(
+            stream.collect_all().await.unwrap(),
+            (0..10).collect::<Vec<_>>()
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_retry_failed_page() {
+        // 本地没有监听的端口，得到一个连接失败的错误
+        let refused = reqwest::get("http://127.0.0.1:9").await.unwrap_err();
+        let refused = Mutex::new(Some(refused));
+        let calls = Mutex::new(Vec::new());
+        let opt = PageOptions::new()
+            .with_page_size(3)
+            .with_concurrency(3)
+            .with_limit(7);
+        let stream = ListStream::new(opt, |p, size| {
+            let first_try = {
+                let mut calls = calls.lock().unwrap();
+                let first_try = !calls.contains(&p);
+                calls.push(p);
+                first_try
+            };
+            let err = (p == 2 && first_try)
+                .then(|| refused.lock().unwrap().take())
+                .flatten();
+            async move {
+                match err {
+                    Some(err) => Err(VxwkError::from(err)),
+                    None => Ok(page(p, size, 100)),
+                }
+            }
+        });
+        assert_eq!(
+            stream.collect_all().await.unwrap(),
+            (0..7).collect::<Vec<_>>()
+        );
+        // 第 2 页重试一次，limit 只需要前 3 页
+        let mut calls = calls.into_inner().unwrap();
+        calls.sort();
+        assert_eq!(calls, [1, 2, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_for_request_errors() {
+        let calls = AtomicU32::new(0);
+        let stream = ListStream::<u32>::new(PageOptions::new(), |_, _| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async move { Err(VxwkError::InvalidResponse("missing field `list`".into())) }
+        });
+        assert!(stream.collect_all().await.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
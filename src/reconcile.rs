@@ -0,0 +1,735 @@
+//! 声明式同步：对比期望状态与服务端数据，生成并执行创建、更新、删除计划
+//!
+//! 期望状态一般放在 JSON/YAML 文件中随代码管理，每类资源用业务键与服务端记录对应：
+//! - 短链、外链：`domainID` + `hash`，声明中必须设置 `hash`
+//! - 活码：`name`
+//!
+//! 声明中为 `None` 的可选字段不参与对比，也不会被更新。
+//! 文件中没有出现的资源类型不会查询，也不会被 `prune` 删除；写成空列表才表示清空该类资源。
+//!
+//! ```no_run
+//! use vxwk_rs_sdk::reconcile::{DesiredState, PlanOptions};
+//! use vxwk_rs_sdk::{BatchOptions, VxwkAPI, VxwkError};
+//!
+//! # async fn run(api: VxwkAPI) -> Result<(), VxwkError> {
+//! let desired = DesiredState::from_path("vxwk.json")?;
+//! let plan = api.plan(&desired, PlanOptions::new().with_prune(true)).await?;
+//! print!("{}", plan);
+//! let report = api
+//!     .apply(&plan, BatchOptions::new(), |p| {
+//!         println!("[{}/{}] {} {} {}", p.done, p.total, p.kind, p.resource, p.key)
+//!     })
+//!     .await;
+//! assert!(report.is_success());
+//! # Ok(())
+//! # }
+//! ```
+use crate::batch::{self, BatchOptions};
+use crate::model::external::{CreateExternalLink, ExternalLink, UpdateExternalLink};
+use crate::model::livecode::{CreateLiveCode, LiveCode, UpdateLiveCode};
+use crate::model::shortlink::{CreateShortLink, ShortLink, UpdateShortLink};
+use crate::paginate::PageOptions;
+use crate::resource::{self, ExternalLinkResource, LiveCodeResource, Resource, ShortLinkResource};
+use crate::validate::{Validate, Validator};
+use crate::{VxwkAPI, VxwkError};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// 查询服务端数据时每页拉取的条数
+const PLAN_PAGE_SIZE: u32 = 100;
+
+/// 期望状态，为 `None` 的资源类型不受管理
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DesiredState {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_links: Option<Vec<CreateShortLink>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_links: Option<Vec<CreateExternalLink>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub live_codes: Option<Vec<CreateLiveCode>>,
+}
+
+impl DesiredState {
+    pub fn from_json(content: &str) -> Result<Self, VxwkError> {
+        serde_json::from_str(content).map_err(invalid_state)
+    }
+
+    #[cfg(feature = "yaml")]
+    pub fn from_yaml(content: &str) -> Result<Self, VxwkError> {
+        serde_yaml::from_str(content).map_err(invalid_state)
+    }
+
+    /// 读取文件，`.yaml`/`.yml` 按 YAML 解析（需要 `yaml` feature），其它按 JSON 解析
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, VxwkError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|err| {
+            VxwkError::InvalidRequest(format!("read `{}` failed: {}", path.display(), err))
+        })?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            #[cfg(feature = "yaml")]
+            Some("yaml" | "yml") => Self::from_yaml(&content),
+            #[cfg(not(feature = "yaml"))]
+            Some("yaml" | "yml") => Err(VxwkError::InvalidRequest(
+                "YAML desired state requires the `yaml` feature".to_string(),
+            )),
+            _ => Self::from_json(&content),
+        }
+    }
+}
+
+fn invalid_state(err: impl fmt::Display) -> VxwkError {
+    VxwkError::InvalidRequest(format!("invalid desired state: {}", err))
+}
+
+/// 可以声明式同步的资源
+pub trait Reconcile: Resource {
+    /// 服务端记录的业务键
+    fn key(model: &Self::Model) -> String;
+
+    /// 声明的业务键，无法确定业务键时返回错误
+    fn desired_key(req: &Self::Create) -> Result<String, VxwkError>;
+
+    /// 把服务端记录更新为声明所需的字段，已一致时返回 `None`
+    fn diff(desired: &Self::Create, current: &Self::Model) -> Option<Self::Update>;
+}
+
+/// 声明中必须设置 `hash`
+fn hash_key(domain_id: &str, hash: Option<&str>) -> Result<String, VxwkError> {
+    let hash = hash.unwrap_or_default();
+    let mut validator = Validator::new();
+    validator.required("hash", hash);
+    validator.finish()?;
+    Ok(format!("{}/{}", domain_id, hash))
+}
+
+/// 声明的值与当前值不同时返回声明的值
+fn changed<T: PartialEq + Clone>(desired: &T, current: &T) -> Option<T> {
+    (desired != current).then(|| desired.clone())
+}
+
+/// 声明为 `None` 时不管理该字段
+fn changed_opt<T: PartialEq + Clone>(desired: &Option<T>, current: &T) -> Option<T> {
+    desired
+        .as_ref()
+        .and_then(|desired| changed(desired, current))
+}
+
+impl Reconcile for ShortLinkResource {
+    fn key(model: &ShortLink) -> String {
+        format!("{}/{}", model.domain_id, model.hash)
+    }
+
+    fn desired_key(req: &CreateShortLink) -> Result<String, VxwkError> {
+        hash_key(&req.domain_id, req.hash.as_deref())
+    }
+
+    fn diff(desired: &CreateShortLink, current: &ShortLink) -> Option<UpdateShortLink> {
+        let update = UpdateShortLink {
+            link: changed(&desired.link, &current.link),
+            title: changed_opt(&desired.title, &current.title),
+            describe: changed_opt(&desired.describe, &current.describe),
+            ..Default::default()
+        };
+        (update != UpdateShortLink::default()).then_some(update)
+    }
+}
+
+impl Reconcile for ExternalLinkResource {
+    fn key(model: &ExternalLink) -> String {
+        format!(
+            "{}/{}",
+            model.domain_id,
+            model.hash.as_deref().unwrap_or_default()
+        )
+    }
+
+    fn desired_key(req: &CreateExternalLink) -> Result<String, VxwkError> {
+        hash_key(&req.domain_id, req.hash.as_deref())
+    }
+
+    fn diff(desired: &CreateExternalLink, current: &ExternalLink) -> Option<UpdateExternalLink> {
+        let update = UpdateExternalLink {
+            title: changed(&desired.title, &current.title),
+            describe: changed(&desired.describe, &current.describe),
+            tips: desired
+                .tips
+                .as_ref()
+                .filter(|tips| current.tips.as_ref() != Some(*tips))
+                .cloned(),
+            img: desired
+                .img
+                .as_ref()
+                .filter(|img| current.img.as_ref() != Some(*img))
+                .cloned(),
+            link_type: changed(&desired.link_type, &current.link_type),
+            start_at: changed(&desired.start_at, &current.start_at),
+            stop_at: changed(&desired.stop_at, &current.stop_at),
+            link: changed(&desired.link, &current.link),
+            test_mode: changed(&desired.test_mode, &current.test_mode),
+            style: changed(&desired.style, &current.style),
+            ..Default::default()
+        };
+        (update != UpdateExternalLink::default()).then_some(update)
+    }
+}
+
+impl Reconcile for LiveCodeResource {
+    fn key(model: &LiveCode) -> String {
+        model.name.clone()
+    }
+
+    fn desired_key(req: &CreateLiveCode) -> Result<String, VxwkError> {
+        let mut validator = Validator::new();
+        validator.required("name", &req.name);
+        validator.finish()?;
+        Ok(req.name.clone())
+    }
+
+    fn diff(desired: &CreateLiveCode, current: &LiveCode) -> Option<UpdateLiveCode> {
+        let update = UpdateLiveCode {
+            describe: changed_opt(&desired.describe, &current.describe),
+            file_id: desired
+                .file_id
+                .as_ref()
+                .filter(|fid| current.file_id.as_ref() != Some(*fid))
+                .cloned(),
+            ..Default::default()
+        };
+        (update != UpdateLiveCode::default()).then_some(update)
+    }
+}
+
+/// 变更类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    Create,
+    Update,
+    Delete,
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ChangeKind::Create => "create",
+            ChangeKind::Update => "update",
+            ChangeKind::Delete => "delete",
+        })
+    }
+}
+
+/// 单条变更，`key` 为业务键
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change<C, U> {
+    Create {
+        key: String,
+        req: C,
+    },
+    Update {
+        key: String,
+        id: String,
+        req: U,
+    },
+    /// 服务端存在但未声明的记录，只在开启 `prune` 时生成
+    Delete {
+        key: String,
+        id: String,
+    },
+}
+
+/// 资源 `R` 的变更
+pub type ChangeOf<R> = Change<<R as Resource>::Create, <R as Resource>::Update>;
+
+impl<C, U> Change<C, U> {
+    pub fn kind(&self) -> ChangeKind {
+        match self {
+            Change::Create { .. } => ChangeKind::Create,
+            Change::Update { .. } => ChangeKind::Update,
+            Change::Delete { .. } => ChangeKind::Delete,
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        match self {
+            Change::Create { key, .. }
+            | Change::Update { key, .. }
+            | Change::Delete { key, .. } => key,
+        }
+    }
+}
+
+/// 生成计划的选项
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlanOptions {
+    /// 删除服务端存在但未声明的记录
+    pub prune: bool,
+}
+
+impl PlanOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_prune(mut self, prune: bool) -> Self {
+        self.prune = prune;
+        self
+    }
+}
+
+/// 同步计划，每类资源内先创建、更新，最后删除
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Plan {
+    pub short_links: Vec<ChangeOf<ShortLinkResource>>,
+    pub external_links: Vec<ChangeOf<ExternalLinkResource>>,
+    pub live_codes: Vec<ChangeOf<LiveCodeResource>>,
+}
+
+impl Plan {
+    /// 所有变更的 `(资源名称, 变更类型, 业务键)`
+    pub fn changes(&self) -> Vec<(&'static str, ChangeKind, &str)> {
+        let mut changes = Vec::new();
+        describe::<ShortLinkResource>(&self.short_links, &mut changes);
+        describe::<ExternalLinkResource>(&self.external_links, &mut changes);
+        describe::<LiveCodeResource>(&self.live_codes, &mut changes);
+        changes
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+fn describe<'p, R: Resource>(
+    changes: &'p [ChangeOf<R>],
+    out: &mut Vec<(&'static str, ChangeKind, &'p str)>,
+) {
+    out.extend(changes.iter().map(|c| (R::NAME, c.kind(), c.key())));
+}
+
+/// 每行一条变更，`+` 创建、`~` 更新、`-` 删除
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (resource, kind, key) in self.changes() {
+            let sign = match kind {
+                ChangeKind::Create => '+',
+                ChangeKind::Update => '~',
+                ChangeKind::Delete => '-',
+            };
+            writeln!(f, "{} {} {}", sign, resource, key)?;
+        }
+        Ok(())
+    }
+}
+
+/// 执行进度，每条变更完成后回调一次
+#[derive(Debug)]
+pub struct Progress<'p> {
+    pub resource: &'static str,
+    pub kind: ChangeKind,
+    pub key: &'p str,
+    /// 已完成的变更数，包含本条
+    pub done: usize,
+    pub total: usize,
+    pub error: Option<&'p VxwkError>,
+}
+
+/// 单条变更的执行结果
+#[derive(Debug)]
+pub struct Outcome {
+    pub resource: &'static str,
+    pub kind: ChangeKind,
+    pub key: String,
+    pub result: Result<(), VxwkError>,
+}
+
+/// 执行结果，顺序与计划一致
+#[derive(Debug, Default)]
+pub struct ApplyReport {
+    pub outcomes: Vec<Outcome>,
+}
+
+impl ApplyReport {
+    pub fn is_success(&self) -> bool {
+        self.outcomes.iter().all(|o| o.result.is_ok())
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &Outcome> {
+        self.outcomes.iter().filter(|o| o.result.is_err())
+    }
+}
+
+impl VxwkAPI {
+    /// 对比期望状态与服务端数据，生成同步计划，不会修改任何数据
+    ///
+    /// 声明会先全部校验，业务键重复或字段不合法时直接返回错误；
+    /// 服务端同一声明业务键有多条记录时也返回错误，需要先手动清理
+    pub async fn plan(&self, desired: &DesiredState, opt: PlanOptions) -> Result<Plan, VxwkError> {
+        Ok(Plan {
+            short_links: plan_resource::<ShortLinkResource>(
+                self,
+                desired.short_links.as_deref(),
+                opt,
+            )
+            .await?,
+            external_links: plan_resource::<ExternalLinkResource>(
+                self,
+                desired.external_links.as_deref(),
+                opt,
+            )
+            .await?,
+            live_codes: plan_resource::<LiveCodeResource>(self, desired.live_codes.as_deref(), opt)
+                .await?,
+        })
+    }
+
+    /// 执行同步计划，`progress` 在每条变更完成后调用
+    ///
+    /// 每类资源的创建、更新全部完成后才开始删除，开启 `stop_on_error` 时前面出错就不再删除。
+    /// 并发数与出错后是否跳过剩余变更由 `opt` 控制，被跳过的变更结果为 [`VxwkError::Skipped`]
+    pub async fn apply<F>(&self, plan: &Plan, opt: BatchOptions, progress: F) -> ApplyReport
+    where
+        F: Fn(&Progress<'_>) + Sync,
+    {
+        let ctx = ApplyContext {
+            api: self,
+            opt,
+            total: plan.len(),
+            done: AtomicUsize::new(0),
+            progress,
+        };
+        let mut report = ApplyReport::default();
+        ctx.run::<ShortLinkResource>(&plan.short_links, &mut report)
+            .await;
+        ctx.run::<ExternalLinkResource>(&plan.external_links, &mut report)
+            .await;
+        ctx.run::<LiveCodeResource>(&plan.live_codes, &mut report)
+            .await;
+        report
+    }
+}
+
+/// 生成单类资源的变更，`desired` 为 `None` 时不查询服务端，也不生成变更
+pub(crate) async fn plan_resource<R>(
+    api: &VxwkAPI,
+    desired: Option<&[R::Create]>,
+    opt: PlanOptions,
+) -> Result<Vec<ChangeOf<R>>, VxwkError>
+where
+    R: Reconcile,
+    R::Create: Clone,
+    R::Filter: Default,
+{
+    let Some(desired) = desired else {
+        return Ok(Vec::new());
+    };
+    let mut keys = HashMap::new();
+    for (index, req) in desired.iter().enumerate() {
+        req.validate()?;
+        let key = R::desired_key(req)?;
+        if keys.insert(key.clone(), index).is_some() {
+            return Err(VxwkError::InvalidRequest(format!(
+                "duplicate {} `{}` in desired state",
+                R::NAME,
+                key
+            )));
+        }
+    }
+
+    // 服务端同一声明业务键有多条记录时无法确定对应哪一条，直接返回错误
+    let mut current: HashMap<String, R::Model> = HashMap::new();
+    let mut undeclared = Vec::new();
+    let page = PageOptions::new().with_page_size(PLAN_PAGE_SIZE);
+    let mut models = resource::stream::<R>(api, R::Filter::default(), page);
+    while let Some(model) = models.next().await {
+        let model = model?;
+        let key = R::key(&model);
+        if !keys.contains_key(&key) {
+            undeclared.push((key, model));
+        } else if let Some(first) = current.get(&key) {
+            return Err(VxwkError::InvalidResponse(format!(
+                "{} `{}` matches multiple records on the server: `{}`, `{}`",
+                R::NAME,
+                key,
+                R::id(first),
+                R::id(&model)
+            )));
+        } else {
+            current.insert(key, model);
+        }
+    }
+
+    let mut changes = Vec::new();
+    for req in desired {
+        let key = R::desired_key(req)?;
+        match current.get(&key) {
+            None => changes.push(Change::Create {
+                key,
+                req: req.clone(),
+            }),
+            Some(model) => {
+                if let Some(update) = R::diff(req, model) {
+                    changes.push(Change::Update {
+                        key,
+                        id: R::id(model).to_string(),
+                        req: update,
+                    });
+                }
+            }
+        }
+    }
+    if opt.prune {
+        changes.extend(undeclared.into_iter().map(|(key, model)| Change::Delete {
+            key,
+            id: R::id(&model).to_string(),
+        }));
+    }
+    Ok(changes)
+}
+
+struct ApplyContext<'a, F> {
+    api: &'a VxwkAPI,
+    opt: BatchOptions,
+    total: usize,
+    done: AtomicUsize,
+    progress: F,
+}
+
+impl<F> ApplyContext<'_, F>
+where
+    F: Fn(&Progress<'_>) + Sync,
+{
+    async fn run<R: Reconcile>(&self, changes: &[ChangeOf<R>], report: &mut ApplyReport) {
+        let (deletes, writes): (Vec<_>, Vec<_>) = changes
+            .iter()
+            .enumerate()
+            .partition(|(_, change)| change.kind() == ChangeKind::Delete);
+        // 前一类资源已经出错时，后面的资源整体跳过
+        let skip = self.opt.stop_on_error && !report.is_success();
+        let mut results = self.phase::<R>(&writes, skip).await;
+        // 删除在创建、更新全部完成后才开始，避免与同一业务键的创建交错
+        let skip = skip || self.opt.stop_on_error && results.iter().any(Result::is_err);
+        results.extend(self.phase::<R>(&deletes, skip).await);
+
+        let mut outcomes: Vec<_> = writes.into_iter().chain(deletes).zip(results).collect();
+        outcomes.sort_by_key(|((index, _), _)| *index);
+        report
+            .outcomes
+            .extend(outcomes.into_iter().map(|((_, change), result)| Outcome {
+                resource: R::NAME,
+                kind: change.kind(),
+                key: change.key().to_string(),
+                result,
+            }));
+    }
+
+    /// 并发执行一组变更，`skip` 为 `true` 时全部跳过
+    async fn phase<R: Reconcile>(
+        &self,
+        changes: &[(usize, &ChangeOf<R>)],
+        skip: bool,
+    ) -> Vec<Result<(), VxwkError>> {
+        batch::run(changes, self.opt, |(_, change)| async move {
+            let result = if skip {
+                Err(VxwkError::Skipped)
+            } else {
+                self.execute::<R>(change).await
+            };
+            (self.progress)(&Progress {
+                resource: R::NAME,
+                kind: change.kind(),
+                key: change.key(),
+                done: self.done.fetch_add(1, Ordering::SeqCst) + 1,
+                total: self.total,
+                error: result.as_ref().err(),
+            });
+            result
+        })
+        .await
+    }
+
+    async fn execute<R: Reconcile>(&self, change: &ChangeOf<R>) -> Result<(), VxwkError> {
+        match change {
+            Change::Create { req, .. } => resource::create::<R>(self.api, req).await.map(drop),
+            Change::Update { id, req, .. } => {
+                resource::update::<R>(self.api, id, req).await.map(drop)
+            }
+            Change::Delete { id, .. } => resource::delete::<R>(self.api, id).await.map(drop),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Change, ChangeKind, DesiredState, Plan, PlanOptions, Reconcile};
+    use crate::model::livecode::LiveCode;
+    use crate::model::shortlink::{CreateShortLink, ShortLink, UpdateShortLink};
+    use crate::resource::{LiveCodeResource, ShortLinkResource};
+    use crate::{BatchOptions, VxwkAPI, VxwkConfig, VxwkError};
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_parse_desired_state() {
+        let state = DesiredState::from_json(
+            r#"{"short_links": [{"domainID": "d", "link": "https://a.com", "hash": "spring"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            state.short_links.unwrap()[0].hash.as_deref(),
+            Some("spring")
+        );
+        assert!(state.live_codes.is_none());
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_parse_yaml_desired_state() {
+        let state =
+            DesiredState::from_yaml("live_codes:\n  - name: 门店\n    describe: 一楼\n").unwrap();
+        assert_eq!(
+            state.live_codes.unwrap()[0].describe.as_deref(),
+            Some("一楼")
+        );
+    }
+
+    #[test]
+    fn test_short_link_diff() {
+        let current: ShortLink = serde_json::from_value(json!({
+            "id": "1", "domainID": "d", "link": "https://a.com", "hash": "spring", "title": "旧标题"
+        }))
+        .unwrap();
+        let same = CreateShortLink::new("d", "https://a.com").with_hash("spring");
+        assert_eq!(ShortLinkResource::key(&current), "d/spring");
+        assert_eq!(ShortLinkResource::desired_key(&same).unwrap(), "d/spring");
+        // 未声明 title 时不管理该字段
+        assert_eq!(ShortLinkResource::diff(&same, &current), None);
+
+        let changed = same.clone().with_title("新标题");
+        assert_eq!(
+            ShortLinkResource::diff(&changed, &current),
+            Some(UpdateShortLink::new().with_title("新标题"))
+        );
+        assert!(
+            ShortLinkResource::desired_key(&CreateShortLink::new("d", "https://a.com")).is_err()
+        );
+    }
+
+    #[test]
+    fn test_live_code_key_is_name() {
+        let current = LiveCode {
+            id: "1".to_string(),
+            name: "门店".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(LiveCodeResource::key(&current), "门店");
+    }
+
+    #[tokio::test]
+    async fn test_omitted_section_is_never_pruned() {
+        // 节点不可用，任何查询都会失败，未声明的资源类型必须不发请求
+        let config = VxwkConfig::new(
+            "key".to_string(),
+            "secret".to_string(),
+            "http://127.0.0.1:9".to_string(),
+        );
+        let api = VxwkAPI::new(config).unwrap();
+        let desired = DesiredState::from_json("{}").unwrap();
+        let plan = api
+            .plan(&desired, PlanOptions::new().with_prune(true))
+            .await
+            .unwrap();
+        assert!(plan.is_empty());
+        assert!(plan
+            .changes()
+            .into_iter()
+            .all(|(_, kind, _)| kind != ChangeKind::Delete));
+    }
+
+    const DOMAIN: &str = "8f1d2c3e-0a1b-4c2d-8e3f-0123456789ab";
+
+    /// 记录请求路径的本地节点，创建接口返回 `create`
+    async fn recording_api(create: &'static str) -> (VxwkAPI, Arc<Mutex<Vec<String>>>) {
+        let paths = Arc::new(Mutex::new(Vec::new()));
+        let record = paths.clone();
+        let addr = crate::test::serve(move |line| {
+            let path = line.split(' ').nth(1).unwrap_or_default();
+            let path = path.split('?').next().unwrap_or_default().to_string();
+            let body = if path.ends_with("/create") {
+                create
+            } else {
+                r#"{"code":200,"data":{}}"#
+            };
+            record.lock().unwrap().push(path);
+            ("200 OK", body)
+        })
+        .await;
+        let config = VxwkConfig::new("key".to_string(), "secret".to_string(), addr);
+        (VxwkAPI::new(config).unwrap(), paths)
+    }
+
+    #[tokio::test]
+    async fn test_delete_after_create() {
+        // 计划中删除排在前面，执行时仍要等创建完成
+        let plan = Plan {
+            short_links: vec![
+                Change::Delete {
+                    key: "d/old".to_string(),
+                    id: "1".to_string(),
+                },
+                Change::Create {
+                    key: "d/new".to_string(),
+                    req: CreateShortLink::new(DOMAIN, "https://a.com").with_hash("new"),
+                },
+            ],
+            ..Default::default()
+        };
+        let opt = BatchOptions::new().with_concurrency(4);
+
+        let (api, paths) = recording_api(r#"{"code":200,"data":{"id":"2"}}"#).await;
+        let report = api.apply(&plan, opt, |_| {}).await;
+        assert!(report.is_success());
+        assert_eq!(report.outcomes[0].kind, ChangeKind::Delete);
+        assert_eq!(
+            *paths.lock().unwrap(),
+            [
+                "/api/v1/user/shortlink/create",
+                "/api/v1/user/shortlink/delete"
+            ]
+        );
+
+        // 创建失败后不再删除
+        let (api, paths) = recording_api(r#"{"code":500,"msg":"busy"}"#).await;
+        let opt = opt.with_stop_on_error(true);
+        let report = api.apply(&plan, opt, |_| {}).await;
+        assert!(matches!(report.outcomes[0].result, Err(VxwkError::Skipped)));
+        assert_eq!(*paths.lock().unwrap(), ["/api/v1/user/shortlink/create"]);
+    }
+
+    #[tokio::test]
+    async fn test_duplicate_server_records_are_rejected() {
+        let addr = crate::test::serve(|_| {
+            (
+                "200 OK",
+                r#"{"code":200,"data":{"total":3,"list":[
+                    {"id":"1","name":"门店"},{"id":"2","name":"门店"},{"id":"3","name":"旧活动"}
+                ]}}"#,
+            )
+        })
+        .await;
+        let config = VxwkConfig::new("key".to_string(), "secret".to_string(), addr);
+        let api = VxwkAPI::new(config).unwrap();
+        let desired = DesiredState::from_json(r#"{"live_codes": [{"name": "门店"}]}"#).unwrap();
+        // 不能把声明过的重复记录当成未声明记录删除
+        let res = api
+            .plan(&desired, PlanOptions::new().with_prune(true))
+            .await;
+        assert!(matches!(res, Err(VxwkError::InvalidResponse(_))));
+    }
+}
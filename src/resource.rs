@@ -0,0 +1,373 @@
+//! 统一的资源抽象，便于对所有资源做通用处理（备份、同步、审计等）
+//!
+//! ```no_run
+//! # #[cfg(feature = "short_link")]
+//! # mod example {
+//! use vxwk_rs_sdk::resource::{self, Resource, ShortLinkResource};
+//! use vxwk_rs_sdk::{ShortLinkListFilter, VxwkAPI, VxwkError};
+//!
+//! // 对任意资源统计总数
+//! async fn count<R: Resource>(api: &VxwkAPI, filter: &R::Filter) -> Result<u64, VxwkError> {
+//!     Ok(resource::list::<R>(api, filter).await?.data.total)
+//! }
+//!
+//! # async fn run(api: VxwkAPI) -> Result<(), VxwkError> {
+//! let total = count::<ShortLinkResource>(&api, &ShortLinkListFilter::new()).await?;
+//! # Ok(())
+//! # }
+//! # }
+//! # fn main() {}
+//! ```
+use crate::batch::{self, BatchOptions};
+#[cfg(feature = "dy_card")]
+use crate::model::dycard::{CreateDyCard, DyCard, DyCardListFilter, UpdateDyCard};
+#[cfg(feature = "external")]
+use crate::model::external::{
+    CreateExternalLink, ExternalLink, ExternalLinkListFilter, UpdateExternalLink,
+};
+#[cfg(feature = "live_code")]
+use crate::model::livecode::{CreateLiveCode, LiveCode, LiveCodeListFilter, UpdateLiveCode};
+#[cfg(feature = "live_code_file")]
+use crate::model::livecodefile::{
+    CreateLiveCodeFile, LiveCodeFile, LiveCodeFileListFilter, UpdateLiveCodeFile,
+    UpdateLiveCodeNameReq, UploadedFile,
+};
+use crate::model::query::ListFilter;
+#[cfg(feature = "short_link")]
+use crate::model::shortlink::{CreateShortLink, ShortLink, ShortLinkListFilter, UpdateShortLink};
+#[cfg(feature = "wx_card")]
+use crate::model::wxcard::{CreateWxCard, UpdateWxCard, WxCard, WxCardListFilter};
+use crate::model::{with_id, ApiResponse, Page};
+use crate::paginate::{ListStream, PageOptions};
+use crate::validate::Validate;
+use crate::{VxwkAPI, VxwkError};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// 一类资源的模型、请求类型与接口地址
+pub trait Resource {
+    /// 列表与详情返回的模型
+    type Model: DeserializeOwned + Send + 'static;
+    /// 创建请求
+    type Create: Serialize + Validate + Sync;
+    /// 创建接口返回的数据
+    type Created: DeserializeOwned;
+    /// 更新请求
+    type Update: Serialize + Validate + Sync;
+    /// 列表筛选条件
+    type Filter: ListFilter + 'static;
+
+    /// 资源名称，用于日志与备份文件名
+    const NAME: &'static str;
+    const LIST_PATH: &'static str;
+    const GET_PATH: &'static str;
+    const CREATE_PATH: &'static str;
+    const UPDATE_PATH: &'static str;
+    const DELETE_PATH: &'static str;
+    /// 详情接口是否使用 POST，默认使用 GET 并把 `id` 放在查询参数中
+    const GET_BY_POST: bool = false;
+
+    /// 模型的 id
+    fn id(model: &Self::Model) -> &str;
+
+    /// 更新接口的请求体，默认把 `id` 合并进 `req`
+    fn update_body(id: &str, req: &Self::Update) -> Result<serde_json::Value, VxwkError> {
+        with_id(id, req)
+    }
+}
+
+/// 资源列表，按类型化的筛选条件查询
+pub async fn list<R: Resource>(
+    api: &VxwkAPI,
+    filter: &R::Filter,
+) -> Result<ApiResponse<Page<R::Model>>, VxwkError> {
+    api.list_typed(R::LIST_PATH, filter).await
+}
+
+/// 按筛选条件逐页拉取全部资源，返回按需请求的流
+///
+/// `filter` 中的分页字段会被 `opt` 覆盖
+pub fn stream<R: Resource>(
+    api: &VxwkAPI,
+    filter: R::Filter,
+    opt: PageOptions,
+) -> ListStream<'_, R::Model> {
+    ListStream::new(opt, move |page, page_size| {
+        let filter = filter.clone().with_page(page, page_size);
+        async move { Ok(list::<R>(api, &filter).await?.data) }
+    })
+}
+
+/// 资源详情
+pub async fn get<R: Resource>(api: &VxwkAPI, id: &str) -> Result<ApiResponse<R::Model>, VxwkError> {
+    let params = HashMap::from([("id", id)]);
+    if R::GET_BY_POST {
+        api.post_typed(R::GET_PATH, &params).await
+    } else {
+        api.get_typed(R::GET_PATH, params).await
+    }
+}
+
+/// 创建资源，发送前校验字段
+pub async fn create<R: Resource>(
+    api: &VxwkAPI,
+    req: &R::Create,
+) -> Result<ApiResponse<R::Created>, VxwkError> {
+    req.validate()?;
+    api.post_typed(R::CREATE_PATH, req).await
+}
+
+/// 更新资源，发送前校验字段
+pub async fn update<R: Resource>(
+    api: &VxwkAPI,
+    id: &str,
+    req: &R::Update,
+) -> Result<ApiResponse<()>, VxwkError> {
+    req.validate()?;
+    let res: ApiResponse<IgnoredAny> = api
+        .post_typed(R::UPDATE_PATH, &R::update_body(id, req)?)
+        .await?;
+    Ok(res.map(drop))
+}
+
+/// 删除资源
+pub async fn delete<R: Resource>(api: &VxwkAPI, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+    let body = HashMap::from([("id", id)]);
+    let res: ApiResponse<IgnoredAny> = api.post_typed(R::DELETE_PATH, &body).await?;
+    Ok(res.map(drop))
+}
+
+/// 批量创建，按输入顺序返回每一项的结果
+pub async fn batch_create<R: Resource>(
+    api: &VxwkAPI,
+    reqs: &[R::Create],
+    opt: BatchOptions,
+) -> Vec<Result<ApiResponse<R::Created>, VxwkError>> {
+    batch::run(reqs, opt, |req| create::<R>(api, req)).await
+}
+
+/// 批量更新，`items` 为 `(id, 更新请求)`，按输入顺序返回每一项的结果
+pub async fn batch_update<R, S>(
+    api: &VxwkAPI,
+    items: &[(S, R::Update)],
+    opt: BatchOptions,
+) -> Vec<Result<ApiResponse<()>, VxwkError>>
+where
+    R: Resource,
+    S: AsRef<str>,
+{
+    batch::run(items, opt, |(id, req)| update::<R>(api, id.as_ref(), req)).await
+}
+
+/// 批量删除，按输入顺序返回每一项的结果
+pub async fn batch_delete<R, S>(
+    api: &VxwkAPI,
+    ids: &[S],
+    opt: BatchOptions,
+) -> Vec<Result<ApiResponse<()>, VxwkError>>
+where
+    R: Resource,
+    S: AsRef<str>,
+{
+    batch::run(ids, opt, |id| delete::<R>(api, id.as_ref())).await
+}
+
+/// 批量获取详情，按输入顺序返回每一项的结果
+pub async fn get_many<R, S>(
+    api: &VxwkAPI,
+    ids: &[S],
+    opt: BatchOptions,
+) -> Vec<Result<ApiResponse<R::Model>, VxwkError>>
+where
+    R: Resource,
+    S: AsRef<str>,
+{
+    batch::run(ids, opt, |id| get::<R>(api, id.as_ref())).await
+}
+
+/// 短链
+#[cfg(feature = "short_link")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ShortLinkResource;
+
+#[cfg(feature = "short_link")]
+impl Resource for ShortLinkResource {
+    type Model = ShortLink;
+    type Create = CreateShortLink;
+    type Created = ShortLink;
+    type Update = UpdateShortLink;
+    type Filter = ShortLinkListFilter;
+
+    const NAME: &'static str = "short_link";
+    const LIST_PATH: &'static str = "/api/v1/user/shortlink/list";
+    const GET_PATH: &'static str = "/api/v1/user/shortlink";
+    const CREATE_PATH: &'static str = "/api/v1/user/shortlink/create";
+    const UPDATE_PATH: &'static str = "/api/v1/user/shortlink/update";
+    const DELETE_PATH: &'static str = "/api/v1/user/shortlink/delete";
+
+    fn id(model: &ShortLink) -> &str {
+        &model.id
+    }
+}
+
+/// 外链
+#[cfg(feature = "external")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExternalLinkResource;
+
+#[cfg(feature = "external")]
+impl Resource for ExternalLinkResource {
+    type Model = ExternalLink;
+    type Create = CreateExternalLink;
+    type Created = ExternalLink;
+    type Update = UpdateExternalLink;
+    type Filter = ExternalLinkListFilter;
+
+    const NAME: &'static str = "external_link";
+    const LIST_PATH: &'static str = "/api/v1/user/external/list";
+    const GET_PATH: &'static str = "/api/v1/user/external";
+    const CREATE_PATH: &'static str = "/api/v1/user/external/create";
+    const UPDATE_PATH: &'static str = "/api/v1/user/external/update";
+    const DELETE_PATH: &'static str = "/api/v1/user/external/delete";
+
+    fn id(model: &ExternalLink) -> &str {
+        &model.id
+    }
+}
+
+/// 抖音卡片
+#[cfg(feature = "dy_card")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DyCardResource;
+
+#[cfg(feature = "dy_card")]
+impl Resource for DyCardResource {
+    type Model = DyCard;
+    type Create = CreateDyCard;
+    type Created = DyCard;
+    type Update = UpdateDyCard;
+    type Filter = DyCardListFilter;
+
+    const NAME: &'static str = "dy_card";
+    const LIST_PATH: &'static str = "/api/v1/user/carddy/list";
+    const GET_PATH: &'static str = "/api/v1/user/carddy";
+    const CREATE_PATH: &'static str = "/api/v1/user/carddy";
+    const UPDATE_PATH: &'static str = "/api/v1/user/carddy/update";
+    const DELETE_PATH: &'static str = "/api/v1/user/carddy/delete";
+
+    fn id(model: &DyCard) -> &str {
+        &model.id
+    }
+}
+
+/// 微信卡片
+#[cfg(feature = "wx_card")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WxCardResource;
+
+#[cfg(feature = "wx_card")]
+impl Resource for WxCardResource {
+    type Model = WxCard;
+    type Create = CreateWxCard;
+    type Created = WxCard;
+    type Update = UpdateWxCard;
+    type Filter = WxCardListFilter;
+
+    const NAME: &'static str = "wx_card";
+    const LIST_PATH: &'static str = "/api/v1/user/wxcard";
+    const GET_PATH: &'static str = "/api/v1/user/cardwx";
+    const CREATE_PATH: &'static str = "/api/v1/user/wxcard";
+    const UPDATE_PATH: &'static str = "/api/v1/user/wxcard/update";
+    const DELETE_PATH: &'static str = "/api/v1/user/wxcard/delete";
+
+    fn id(model: &WxCard) -> &str {
+        &model.id
+    }
+}
+
+/// 活码
+#[cfg(feature = "live_code")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LiveCodeResource;
+
+#[cfg(feature = "live_code")]
+impl Resource for LiveCodeResource {
+    type Model = LiveCode;
+    type Create = CreateLiveCode;
+    type Created = LiveCode;
+    type Update = UpdateLiveCode;
+    type Filter = LiveCodeListFilter;
+
+    const NAME: &'static str = "live_code";
+    const LIST_PATH: &'static str = "/api/v1/user/livecode/list";
+    const GET_PATH: &'static str = "/api/v1/user/livecode/info";
+    const CREATE_PATH: &'static str = "/api/v1/user/livecode/create";
+    const UPDATE_PATH: &'static str = "/api/v1/user/livecode/update";
+    const DELETE_PATH: &'static str = "/api/v1/user/livecode/delete";
+    const GET_BY_POST: bool = true;
+
+    fn id(model: &LiveCode) -> &str {
+        &model.id
+    }
+}
+
+/// 活码文件，创建即上传，更新只能修改名称
+#[cfg(feature = "live_code_file")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LiveCodeFileResource;
+
+#[cfg(feature = "live_code_file")]
+impl Resource for LiveCodeFileResource {
+    type Model = LiveCodeFile;
+    type Create = CreateLiveCodeFile;
+    type Created = UploadedFile;
+    type Update = UpdateLiveCodeFile;
+    type Filter = LiveCodeFileListFilter;
+
+    const NAME: &'static str = "live_code_file";
+    const LIST_PATH: &'static str = "/api/v1/user/livecode/file/list";
+    const GET_PATH: &'static str = "/api/v1/user/livecode/file";
+    const CREATE_PATH: &'static str = "/api/v1/user/livecode/file/update";
+    const UPDATE_PATH: &'static str = "/api/v1/user/livecode/file/name/update";
+    const DELETE_PATH: &'static str = "/api/v1/user/livecode/file/delete";
+
+    fn id(model: &LiveCodeFile) -> &str {
+        &model.id
+    }
+
+    /// 改名接口使用 `fid` 而不是 `id`
+    fn update_body(id: &str, req: &UpdateLiveCodeFile) -> Result<serde_json::Value, VxwkError> {
+        let body = UpdateLiveCodeNameReq {
+            fid: id.to_string(),
+            name: req.name.clone(),
+        };
+        serde_json::to_value(body).map_err(|err| VxwkError::InvalidRequest(err.to_string()))
+    }
+}
+
+#[cfg(all(test, feature = "short_link", feature = "live_code_file"))]
+mod test {
+    use super::{LiveCodeFileResource, Resource, ShortLinkResource};
+    use crate::model::livecodefile::UpdateLiveCodeFile;
+    use crate::model::shortlink::UpdateShortLink;
+    use serde_json::json;
+
+    #[test]
+    fn test_default_update_body() {
+        let req = UpdateShortLink::new().with_title("春季");
+        assert_eq!(
+            ShortLinkResource::update_body("42", &req).unwrap(),
+            json!({"id": "42", "title": "春季"})
+        );
+    }
+
+    #[test]
+    fn test_file_update_body_uses_fid() {
+        let req = UpdateLiveCodeFile::new("b.png");
+        assert_eq!(
+            LiveCodeFileResource::update_body("f1", &req).unwrap(),
+            json!({"fid": "f1", "name": "b.png"})
+        );
+    }
+}
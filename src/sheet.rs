@@ -0,0 +1,618 @@
+//! 短链与外链的 CSV/TSV 导入导出
+//!
+//! 列名使用接口中的字段名（如 `domainID`、`createAt`），可以通过 [`SheetOptions::with_column`]
+//! 设置表头显示的名称，导入时按同样的对应关系识别表头。数组字段在单元格中按行分隔。
+//!
+//! 导出默认写入 UTF-8 BOM，Excel 打开中文标题时不会乱码；导入时会自动去掉 BOM，非 UTF-8
+//! 编码的文件会报错。
+//!
+//! ```no_run
+//! use vxwk_rs_sdk::sheet::SheetOptions;
+//! use vxwk_rs_sdk::{BatchOptions, ShortLinkListFilter, VxwkAPI, VxwkError};
+//!
+//! # async fn run(api: VxwkAPI) -> Result<(), VxwkError> {
+//! let opt = SheetOptions::new()
+//!     .with_column("hash", "后缀")
+//!     .with_column("link", "目标地址")
+//!     .with_column("title", "标题");
+//! let file = std::fs::File::create("short_links.csv")?;
+//! api.short_links()
+//!     .export_csv(ShortLinkListFilter::new(), file, &opt)
+//!     .await?;
+//!
+//! let input = std::fs::File::open("short_links.csv")?;
+//! let result = std::fs::File::create("short_links.result.csv")?;
+//! let report = api
+//!     .short_links()
+//!     .import_csv(input, result, &opt, BatchOptions::new())
+//!     .await?;
+//! println!("{} rows failed", report.failures().count());
+//! # Ok(())
+//! # }
+//! ```
+use crate::batch::{self, BatchOptions};
+use crate::paginate::PageOptions;
+use crate::resource::{self, ExternalLinkResource, Resource, ShortLinkResource};
+use crate::validate::Validate;
+use crate::VxwkAPI;
+use crate::VxwkError;
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::fmt;
+use std::io::{Read, Write};
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+/// 导出时每页拉取的条数
+const EXPORT_PAGE_SIZE: u32 = 100;
+/// 数组字段在单元格中的分隔符
+const LIST_SEPARATOR: char = '\n';
+
+/// 一列的字段名与表头
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    pub field: String,
+    pub header: String,
+}
+
+/// 导入导出的选项
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SheetOptions {
+    pub delimiter: u8,
+    /// 导出时写入 UTF-8 BOM
+    pub bom: bool,
+    /// 为空时使用资源的默认列，表头即字段名
+    pub columns: Vec<Column>,
+}
+
+impl Default for SheetOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            bom: true,
+            columns: Vec::new(),
+        }
+    }
+}
+
+impl SheetOptions {
+    /// CSV
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// TSV
+    pub fn tsv() -> Self {
+        Self::default().with_delimiter(b'\t')
+    }
+
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn with_bom(mut self, bom: bool) -> Self {
+        self.bom = bom;
+        self
+    }
+
+    /// 追加一列，`header` 为表头显示的名称
+    pub fn with_column(mut self, field: &str, header: &str) -> Self {
+        self.columns.push(Column {
+            field: field.to_string(),
+            header: header.to_string(),
+        });
+        self
+    }
+
+    /// 追加多列，表头即字段名
+    pub fn with_fields(mut self, fields: &[&str]) -> Self {
+        for field in fields {
+            self = self.with_column(field, field);
+        }
+        self
+    }
+
+    fn columns_for<R: SheetResource>(&self) -> Vec<Column> {
+        if !self.columns.is_empty() {
+            return self.columns.clone();
+        }
+        R::COLUMNS
+            .iter()
+            .map(|field| Column {
+                field: field.to_string(),
+                header: field.to_string(),
+            })
+            .collect()
+    }
+}
+
+/// 单元格的取值类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Text,
+    Integer,
+    /// `true`/`false`，也接受 `1`/`0`
+    Bool,
+    /// 字符串数组，单元格中每行一项
+    List,
+}
+
+/// 支持表格导入导出的资源，创建接口返回的就是资源本身
+pub trait SheetResource: Resource<Created = <Self as Resource>::Model> {
+    /// 默认导出的列
+    const COLUMNS: &'static [&'static str];
+
+    /// 导入时单元格按字段类型解析
+    fn field_kind(field: &str) -> FieldKind {
+        let _ = field;
+        FieldKind::Text
+    }
+}
+
+impl SheetResource for ShortLinkResource {
+    const COLUMNS: &'static [&'static str] = &[
+        "id", "domainID", "hash", "link", "title", "describe", "createAt",
+    ];
+}
+
+impl SheetResource for ExternalLinkResource {
+    const COLUMNS: &'static [&'static str] = &[
+        "id", "domainID", "hash", "type", "title", "describe", "tips", "img", "link", "startAt",
+        "stopAt", "testMode", "style",
+    ];
+
+    fn field_kind(field: &str) -> FieldKind {
+        match field {
+            "type" | "startAt" | "stopAt" => FieldKind::Integer,
+            "testMode" => FieldKind::Bool,
+            "link" => FieldKind::List,
+            _ => FieldKind::Text,
+        }
+    }
+}
+
+/// 导入时单行的处理结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowStatus {
+    Created,
+    Updated,
+    /// 请求失败
+    Failed,
+    /// 校验未通过
+    Invalid,
+    /// 其它行校验未通过，或开启 `stop_on_error` 后前面的请求失败，没有发出请求
+    Skipped,
+}
+
+impl fmt::Display for RowStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            RowStatus::Created => "created",
+            RowStatus::Updated => "updated",
+            RowStatus::Failed => "failed",
+            RowStatus::Invalid => "invalid",
+            RowStatus::Skipped => "skipped",
+        })
+    }
+}
+
+/// 单行的导入结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowResult {
+    /// 在文件中的行号，表头为第 1 行
+    pub line: u64,
+    /// 新建或更新的 id
+    pub id: Option<String>,
+    pub status: RowStatus,
+    pub error: Option<String>,
+}
+
+/// 导入结果，顺序与文件中的行一致
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ImportReport {
+    pub rows: Vec<RowResult>,
+}
+
+impl ImportReport {
+    pub fn is_success(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// 未成功的行
+    pub fn failures(&self) -> impl Iterator<Item = &RowResult> {
+        self.rows
+            .iter()
+            .filter(|row| !matches!(row.status, RowStatus::Created | RowStatus::Updated))
+    }
+}
+
+/// 导出资源 `R` 的全部记录，返回写入的行数
+pub async fn export<R, W>(
+    api: &VxwkAPI,
+    filter: R::Filter,
+    mut writer: W,
+    opt: &SheetOptions,
+) -> Result<usize, VxwkError>
+where
+    R: SheetResource,
+    R::Model: Serialize,
+    W: Write,
+{
+    let columns = opt.columns_for::<R>();
+    if opt.bom {
+        writer.write_all(UTF8_BOM)?;
+    }
+    let mut csv = csv::WriterBuilder::new()
+        .delimiter(opt.delimiter)
+        .from_writer(writer);
+    csv.write_record(columns.iter().map(|c| &c.header))
+        .map_err(write_error)?;
+
+    let page = PageOptions::new().with_page_size(EXPORT_PAGE_SIZE);
+    let mut models = resource::stream::<R>(api, filter, page);
+    let mut count = 0;
+    while let Some(model) = models.next().await {
+        let value = serde_json::to_value(model?)
+            .map_err(|err| VxwkError::InvalidResponse(err.to_string()))?;
+        csv.write_record(columns.iter().map(|c| cell(value.get(&c.field))))
+            .map_err(write_error)?;
+        count += 1;
+    }
+    csv.flush()?;
+    Ok(count)
+}
+
+/// 导入资源 `R`：`id` 列有值的行更新，其它行创建
+///
+/// 先校验全部行，任意一行不合法时不发出任何请求。`result` 中写入原始各列以及
+/// `id`、`status`、`error` 三列，`id` 为新建或更新的记录 id
+pub async fn import<R, I, W>(
+    api: &VxwkAPI,
+    input: I,
+    result: W,
+    opt: &SheetOptions,
+    batch_opt: BatchOptions,
+) -> Result<ImportReport, VxwkError>
+where
+    R: SheetResource,
+    R::Create: Default + DeserializeOwned,
+    R::Update: DeserializeOwned,
+    I: Read,
+    W: Write,
+{
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(opt.delimiter)
+        .from_reader(input);
+    let headers = reader.headers().map_err(read_error)?.clone();
+    let columns = opt.columns_for::<R>();
+    let fields: Vec<Option<String>> = headers
+        .iter()
+        .map(|header| {
+            columns
+                .iter()
+                .find(|c| c.header == header || c.field == header)
+                .map(|c| c.field.clone())
+        })
+        .collect();
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(read_error)?;
+        let line = record.position().map_or(0, |p| p.line());
+        let parsed = parse_row::<R>(&fields, &record);
+        rows.push((line, record, parsed));
+    }
+
+    let valid: Option<Vec<&Row<R::Create, R::Update>>> = rows
+        .iter()
+        .map(|(_, _, parsed)| parsed.as_ref().ok())
+        .collect();
+    let results: Vec<RowResult> = match valid {
+        Some(reqs) => {
+            let outcomes = batch::run(reqs, batch_opt, |row| async move {
+                match row {
+                    Row::Create(req) => resource::create::<R>(api, req)
+                        .await
+                        .map(|res| (R::id(&res.data).to_string(), RowStatus::Created)),
+                    Row::Update(id, req) => resource::update::<R>(api, id, req)
+                        .await
+                        .map(|_| (id.clone(), RowStatus::Updated)),
+                }
+            })
+            .await;
+            rows.iter()
+                .zip(outcomes)
+                .map(|((line, _, _), outcome)| match outcome {
+                    Ok((id, status)) => RowResult {
+                        line: *line,
+                        id: Some(id),
+                        status,
+                        error: None,
+                    },
+                    Err(VxwkError::Skipped) => RowResult {
+                        line: *line,
+                        id: None,
+                        status: RowStatus::Skipped,
+                        error: None,
+                    },
+                    Err(err) => RowResult {
+                        line: *line,
+                        id: None,
+                        status: RowStatus::Failed,
+                        error: Some(err.to_string()),
+                    },
+                })
+                .collect()
+        }
+        None => rows
+            .iter()
+            .map(|(line, _, parsed)| RowResult {
+                line: *line,
+                id: None,
+                status: if parsed.is_err() {
+                    RowStatus::Invalid
+                } else {
+                    RowStatus::Skipped
+                },
+                error: parsed.as_ref().err().map(ToString::to_string),
+            })
+            .collect(),
+    };
+
+    write_result(result, opt, &headers, &fields, &rows, &results)?;
+    Ok(ImportReport { rows: results })
+}
+
+enum Row<C, U> {
+    Create(C),
+    Update(String, U),
+}
+
+/// 把一行转换为创建或更新请求并校验
+fn parse_row<R>(
+    fields: &[Option<String>],
+    record: &csv::StringRecord,
+) -> Result<Row<R::Create, R::Update>, VxwkError>
+where
+    R: SheetResource,
+    R::Create: Default + DeserializeOwned,
+    R::Update: DeserializeOwned,
+{
+    let mut id = None;
+    let mut values = Map::new();
+    for (field, raw) in fields.iter().zip(record.iter()) {
+        let (Some(field), raw) = (field, raw.trim()) else {
+            continue;
+        };
+        if raw.is_empty() {
+            continue;
+        }
+        if field == "id" {
+            id = Some(raw.to_string());
+            continue;
+        }
+        values.insert(field.clone(), parse_cell(field, raw, R::field_kind(field))?);
+    }
+
+    let row = match id {
+        Some(id) => {
+            let req: R::Update = from_map(values)?;
+            req.validate()?;
+            Row::Update(id, req)
+        }
+        None => {
+            // 未填写的字段使用默认值，由校验给出缺失的必填字段
+            let mut create = match serde_json::to_value(R::Create::default()) {
+                Ok(Value::Object(create)) => create,
+                _ => Map::new(),
+            };
+            create.extend(values);
+            let req: R::Create = from_map(create)?;
+            req.validate()?;
+            Row::Create(req)
+        }
+    };
+    Ok(row)
+}
+
+fn parse_cell(field: &str, raw: &str, kind: FieldKind) -> Result<Value, VxwkError> {
+    let invalid = |expected: &str| {
+        VxwkError::InvalidRequest(format!("{}: expected {}, got `{}`", field, expected, raw))
+    };
+    Ok(match kind {
+        FieldKind::Text => Value::String(raw.to_string()),
+        FieldKind::Integer => Value::from(raw.parse::<i64>().map_err(|_| invalid("an integer"))?),
+        FieldKind::Bool => match raw {
+            "true" | "1" => Value::Bool(true),
+            "false" | "0" => Value::Bool(false),
+            _ => return Err(invalid("true or false")),
+        },
+        FieldKind::List => Value::Array(
+            raw.split(LIST_SEPARATOR)
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| Value::String(item.to_string()))
+                .collect(),
+        ),
+    })
+}
+
+fn from_map<T: DeserializeOwned>(values: Map<String, Value>) -> Result<T, VxwkError> {
+    serde_json::from_value(Value::Object(values))
+        .map_err(|err| VxwkError::InvalidRequest(err.to_string()))
+}
+
+/// 导出时单元格的文本
+fn cell(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(text)) => text.clone(),
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(|item| cell(Some(item)))
+            .collect::<Vec<_>>()
+            .join(&LIST_SEPARATOR.to_string()),
+        Some(other) => other.to_string(),
+    }
+}
+
+fn write_result<W: Write, T>(
+    mut result: W,
+    opt: &SheetOptions,
+    headers: &csv::StringRecord,
+    fields: &[Option<String>],
+    rows: &[(u64, csv::StringRecord, T)],
+    results: &[RowResult],
+) -> Result<(), VxwkError> {
+    if opt.bom {
+        result.write_all(UTF8_BOM)?;
+    }
+    let mut csv = csv::WriterBuilder::new()
+        .delimiter(opt.delimiter)
+        .flexible(true)
+        .from_writer(result);
+    let id_index = fields.iter().position(|f| f.as_deref() == Some("id"));
+    let mut header: Vec<&str> = headers.iter().collect();
+    if id_index.is_none() {
+        header.push("id");
+    }
+    header.extend(["status", "error"]);
+    csv.write_record(&header).map_err(write_error)?;
+
+    for ((_, record, _), row) in rows.iter().zip(results) {
+        let mut cells: Vec<String> = record.iter().map(str::to_string).collect();
+        let id = row.id.clone().unwrap_or_default();
+        match id_index {
+            Some(index) if !id.is_empty() => cells[index] = id,
+            Some(_) => {}
+            None => cells.push(id),
+        }
+        cells.push(row.status.to_string());
+        cells.push(row.error.clone().unwrap_or_default());
+        csv.write_record(&cells).map_err(write_error)?;
+    }
+    csv.flush()?;
+    Ok(())
+}
+
+fn read_error(err: csv::Error) -> VxwkError {
+    match err.kind() {
+        csv::ErrorKind::Utf8 { .. } => {
+            VxwkError::InvalidRequest(format!("file must be UTF-8 encoded: {}", err))
+        }
+        _ => VxwkError::InvalidRequest(format!("invalid csv: {}", err)),
+    }
+}
+
+fn write_error(err: csv::Error) -> VxwkError {
+    VxwkError::IoError(err.into())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{cell, import, parse_row, Row, RowStatus, SheetOptions, SheetResource};
+    use crate::resource::{ExternalLinkResource, ShortLinkResource};
+    use crate::{BatchOptions, VxwkAPI, VxwkConfig};
+    use serde_json::json;
+
+    fn fields(names: &[&str]) -> Vec<Option<String>> {
+        names.iter().map(|n| Some(n.to_string())).collect()
+    }
+
+    #[test]
+    fn test_list_cell() {
+        assert_eq!(
+            cell(Some(&json!(["https://a.com", "https://b.com"]))),
+            "https://a.com\nhttps://b.com"
+        );
+        assert_eq!(cell(Some(&json!(3))), "3");
+        assert_eq!(cell(None), "");
+    }
+
+    #[test]
+    fn test_parse_rows() {
+        let fields = fields(&["id", "domainID", "link", "title"]);
+        let record = csv::StringRecord::from(vec![
+            "",
+            "8f1d2c3e-0a1b-4c2d-8e3f-0123456789ab",
+            "https://a.com",
+            "春季活动",
+        ]);
+        match parse_row::<ShortLinkResource>(&fields, &record).unwrap() {
+            Row::Create(req) => assert_eq!(req.title.as_deref(), Some("春季活动")),
+            Row::Update(..) => panic!("expected create"),
+        }
+
+        let record = csv::StringRecord::from(vec!["42", "", "", "新标题"]);
+        match parse_row::<ShortLinkResource>(&fields, &record).unwrap() {
+            Row::Update(id, req) => {
+                assert_eq!(id, "42");
+                assert_eq!(req.link, None);
+            }
+            Row::Create(_) => panic!("expected update"),
+        }
+
+        // 新建时缺少必填字段
+        let record = csv::StringRecord::from(vec!["", "", "https://a.com", ""]);
+        assert!(parse_row::<ShortLinkResource>(&fields, &record).is_err());
+    }
+
+    #[test]
+    fn test_parse_typed_cells() {
+        let fields = fields(&["id", "type", "testMode", "link"]);
+        let record = csv::StringRecord::from(vec!["1", "2", "1", "https://a.com\nhttps://b.com"]);
+        match parse_row::<ExternalLinkResource>(&fields, &record).unwrap() {
+            Row::Update(_, req) => {
+                assert_eq!(req.test_mode, Some(true));
+                assert_eq!(req.link.map(|l| l.len()), Some(2));
+            }
+            Row::Create(_) => panic!("expected update"),
+        }
+        let record = csv::StringRecord::from(vec!["1", "x", "", ""]);
+        assert!(parse_row::<ExternalLinkResource>(&fields, &record).is_err());
+    }
+
+    #[test]
+    fn test_default_columns() {
+        let opt = SheetOptions::tsv();
+        let columns = opt.columns_for::<ShortLinkResource>();
+        assert_eq!(columns.len(), ShortLinkResource::COLUMNS.len());
+        assert_eq!(opt.delimiter, b'\t');
+    }
+
+    #[tokio::test]
+    async fn test_invalid_rows_skip_requests() {
+        let config = VxwkConfig::new(
+            "key".to_string(),
+            "secret".to_string(),
+            "http://127.0.0.1:9".to_string(),
+        );
+        let api = VxwkAPI::new(config).unwrap();
+        let input = "\u{feff}domainID,link,标题\n8f1d2c3e-0a1b-4c2d-8e3f-0123456789ab,https://a.com,春季\n,https://b.com,夏季\n";
+        let opt = SheetOptions::new()
+            .with_fields(&["domainID", "link"])
+            .with_column("title", "标题");
+        let mut result = Vec::new();
+        let report = import::<ShortLinkResource, _, _>(
+            &api,
+            input.as_bytes(),
+            &mut result,
+            &opt,
+            BatchOptions::new(),
+        )
+        .await
+        .unwrap();
+
+        let statuses: Vec<_> = report.rows.iter().map(|r| (r.line, r.status)).collect();
+        assert_eq!(statuses, [(2, RowStatus::Skipped), (3, RowStatus::Invalid)]);
+        let result = String::from_utf8(result).unwrap();
+        let mut lines = result.lines();
+        assert_eq!(
+            lines.next(),
+            Some("\u{feff}domainID,link,标题,id,status,error")
+        );
+        assert!(lines.next().unwrap().ends_with(",春季,,skipped,"));
+    }
+}
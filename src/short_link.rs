@@ -0,0 +1,267 @@
+//! 短链相关api
+use crate::batch::BatchOptions;
+use crate::model::shortlink::{CreateShortLink, ShortLink, ShortLinkListFilter, UpdateShortLink};
+use crate::model::query::ListFilter;
+use crate::model::{with_id, ApiResponse, FindOrCreate, Page};
+use crate::paginate::{ListStream, PageOptions};
+use crate::resource::{self, Resource, ShortLinkResource};
+#[cfg(feature = "csv")]
+use crate::sheet::{self, ImportReport, SheetOptions};
+use crate::validate::Validate;
+use crate::{VxwkAPI, VxwkError, IDEMPOTENCY_KEY_HEADER};
+use futures::StreamExt;
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::Serialize;
+use std::collections::HashMap;
+#[cfg(feature = "csv")]
+use std::io::{Read, Write};
+
+/// 查找已有短链时每页拉取的条数
+const FIND_PAGE_SIZE: u32 = 100;
+
+impl VxwkAPI {
+    /// 短链列表
+    #[deprecated(note = "use `short_links().list()` instead")]
+    pub async fn short_link_list(&self,opt: HashMap<&str, &str>)-> Result<serde_json::Value, VxwkError>{
+        let result = self.get("/api/v1/user/shortlink/list", opt).await?;
+        Ok(result.json().await?)
+    }
+    
+    /// 获取短连接详情
+    #[deprecated(note = "use `short_links().get()` instead")]
+    pub async fn short_link_detail(&self, id: &str, opt: HashMap<&str, &str>) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self
+            .get("/api/v1/user/shortlink", query_params)
+            .await?;
+        Ok(result.json().await?)
+    }
+    /// 创建新的短链
+    #[deprecated(note = "use `short_links().create()` instead")]
+    pub async fn short_link_create(&self, opt: HashMap<&str, &str>) -> Result<serde_json::Value, VxwkError> {
+        let result = self.post("/api/v1/user/shortlink/create", &opt).await?;
+        Ok(result)
+    }
+
+    /// 更新短链
+    #[deprecated(note = "use `short_links().update()` instead")]
+    pub async fn short_link_update(&self, id: &str, opt: HashMap<&str, &str>) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self
+            .post("/api/v1/user/shortlink/update", &query_params)
+            .await?;
+        Ok(result)
+    }
+
+
+    /// 删除短链
+    /// 
+    #[deprecated(note = "use `short_links().delete()` instead")]
+    pub async fn short_link_delete(&self, id: &str) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        let result = self
+            .post("/api/v1/user/shortlink/delete", &query_params)
+            .await?;
+        Ok(result)
+    }
+
+
+    /// 短链接口
+    pub fn short_links(&self) -> ShortLinks<'_> {
+        ShortLinks { api: self }
+    }
+}
+
+/// 短链接口，通过 [`VxwkAPI::short_links`] 获取
+#[derive(Clone, Copy)]
+pub struct ShortLinks<'a> {
+    api: &'a VxwkAPI,
+}
+
+impl<'a> ShortLinks<'a> {
+    /// 短链列表，按类型化的筛选条件查询
+    pub async fn list(
+        &self,
+        filter: &ShortLinkListFilter,
+    ) -> Result<ApiResponse<Page<ShortLink>>, VxwkError> {
+        resource::list::<ShortLinkResource>(self.api, filter).await
+    }
+
+    /// 按筛选条件逐页拉取全部短链，返回按需请求的流
+    ///
+    /// `filter` 中的分页字段会被 `opt` 覆盖
+    pub fn stream(self, filter: ShortLinkListFilter, opt: PageOptions) -> ListStream<'a, ShortLink> {
+        resource::stream::<ShortLinkResource>(self.api, filter, opt)
+    }
+
+    /// 短链详情
+    pub async fn get(&self, id: &str) -> Result<ApiResponse<ShortLink>, VxwkError> {
+        resource::get::<ShortLinkResource>(self.api, id).await
+    }
+
+    /// 创建短链，发送前校验字段
+    pub async fn create(&self, req: &CreateShortLink) -> Result<ApiResponse<ShortLink>, VxwkError> {
+        resource::create::<ShortLinkResource>(self.api, req).await
+    }
+
+    /// 更新短链，发送前校验字段，只发送 `req` 中设置过的字段
+    pub async fn update(
+        &self,
+        id: &str,
+        req: &UpdateShortLink,
+    ) -> Result<ApiResponse<()>, VxwkError> {
+        resource::update::<ShortLinkResource>(self.api, id, req).await
+    }
+
+    /// 删除短链
+    pub async fn delete(&self, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+        resource::delete::<ShortLinkResource>(self.api, id).await
+    }
+
+    /// 幂等创建：同域名下已有与 `req` 匹配的短链时直接返回，否则创建
+    ///
+    /// 匹配规则见 [`CreateShortLink::matches`]。查找时用 `hash`（未设置时用 `link`）作为关键字缩小范围，
+    /// 不会遍历整个域名。设置 `idempotency_key` 时会随创建请求发送
+    /// [`IDEMPOTENCY_KEY_HEADER`]，同一个键在重试时应保持不变
+    /// ```no_run
+    /// # use vxwk_rs_sdk::{CreateShortLink, VxwkAPI};
+    /// # async fn run(api: VxwkAPI) -> Result<(), vxwk_rs_sdk::VxwkError> {
+    /// let req = CreateShortLink::new("8f1d2c3e-0a1b-4c2d-8e3f-0123456789ab", "https://example.com")
+    ///     .with_hash("spring");
+    /// let link = api.short_links().find_or_create(&req, Some("job-42")).await?;
+    /// println!("created: {}, id: {}", link.is_created(), link.into_inner().id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn find_or_create(
+        &self,
+        req: &CreateShortLink,
+        idempotency_key: Option<&str>,
+    ) -> Result<FindOrCreate<ShortLink>, VxwkError> {
+        let keyword = req.hash.as_deref().unwrap_or(&req.link);
+        let filter = ShortLinkListFilter::new().with_keyword(keyword);
+        self.find_or_create_by(req, filter, idempotency_key, |link| req.matches(link))
+            .await
+    }
+
+    /// 幂等创建，由 `matches` 判断已有短链是否就是要创建的那一条，用于按标题等自定义的业务键去重
+    ///
+    /// 在 `filter` 筛选出的短链中查找，域名固定为 `req.domain_id`。
+    /// 大量短链的账号应设置关键字等条件，避免每次都遍历整个域名
+    pub async fn find_or_create_by<F>(
+        &self,
+        req: &CreateShortLink,
+        filter: ShortLinkListFilter,
+        idempotency_key: Option<&str>,
+        matches: F,
+    ) -> Result<FindOrCreate<ShortLink>, VxwkError>
+    where
+        F: Fn(&ShortLink) -> bool,
+    {
+        req.validate()?;
+        let filter = filter.with_domain_id(&req.domain_id);
+        let opt = PageOptions::new().with_page_size(FIND_PAGE_SIZE);
+        let mut links = self.stream(filter, opt);
+        while let Some(link) = links.next().await {
+            let link = link?;
+            if matches(&link) {
+                return Ok(FindOrCreate::Found(link));
+            }
+        }
+
+        let mut headers = HeaderMap::new();
+        if let Some(key) = idempotency_key {
+            let value = HeaderValue::from_str(key).map_err(|_| {
+                VxwkError::InvalidRequest(format!("invalid idempotency key `{}`", key))
+            })?;
+            headers.insert(IDEMPOTENCY_KEY_HEADER, value);
+        }
+        let created = self
+            .api
+            .post_typed_with_headers(ShortLinkResource::CREATE_PATH, req, headers)
+            .await?;
+        Ok(FindOrCreate::Created(created))
+    }
+
+    /// 创建短链，请求体可以是任意可序列化的结构或 `serde_json::Value`
+    pub async fn create_json<T>(&self, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api.post(ShortLinkResource::CREATE_PATH, body).await
+    }
+
+    /// 更新短链，`id` 会合并进请求体，其它字段保留原本的 JSON 类型
+    pub async fn update_json<T>(&self, id: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .post(ShortLinkResource::UPDATE_PATH, &with_id(id, body)?)
+            .await
+    }
+
+    /// 批量创建短链，按输入顺序返回每一项的结果
+    pub async fn batch_create(
+        &self,
+        reqs: &[CreateShortLink],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<ShortLink>, VxwkError>> {
+        resource::batch_create::<ShortLinkResource>(self.api, reqs, opt).await
+    }
+
+    /// 批量更新短链，`items` 为 `(id, 更新请求)`
+    pub async fn batch_update<S: AsRef<str>>(
+        &self,
+        items: &[(S, UpdateShortLink)],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        resource::batch_update::<ShortLinkResource, S>(self.api, items, opt).await
+    }
+
+    /// 批量删除短链
+    pub async fn batch_delete<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        resource::batch_delete::<ShortLinkResource, S>(self.api, ids, opt).await
+    }
+
+    /// 批量获取短链详情
+    pub async fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<ShortLink>, VxwkError>> {
+        resource::get_many::<ShortLinkResource, S>(self.api, ids, opt).await
+    }
+
+    /// 导出短链为 CSV/TSV，返回写入的行数，见 [`sheet`](crate::sheet)
+    #[cfg(feature = "csv")]
+    pub async fn export_csv<W: Write>(
+        &self,
+        filter: ShortLinkListFilter,
+        writer: W,
+        opt: &SheetOptions,
+    ) -> Result<usize, VxwkError> {
+        sheet::export::<ShortLinkResource, W>(self.api, filter, writer, opt).await
+    }
+
+    /// 从 CSV/TSV 导入短链，`id` 列有值的行更新，其它行创建，结果写入 `result`
+    #[cfg(feature = "csv")]
+    pub async fn import_csv<I: Read, W: Write>(
+        &self,
+        input: I,
+        result: W,
+        opt: &SheetOptions,
+        batch_opt: BatchOptions,
+    ) -> Result<ImportReport, VxwkError> {
+        sheet::import::<ShortLinkResource, I, W>(self.api, input, result, opt, batch_opt).await
+    }
+}
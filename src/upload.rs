@@ -0,0 +1,323 @@
+//! 流式上传活码文件，边读取边编码，不在内存中保留完整的文件与 base64 副本
+use crate::model::livecodefile::{
+    check_file, too_large, FileType, UploadOptions, UploadedFile, SNIFF_LEN,
+};
+use crate::model::ApiResponse;
+use crate::resource::{LiveCodeFileResource, Resource};
+use crate::validate::Validator;
+use crate::{VxwkAPI, VxwkError};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use futures::Stream;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Body;
+use std::collections::HashMap;
+use std::future::Future;
+use std::io;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+use tokio::fs::File;
+use tokio::io::{AsyncRead, ReadBuf};
+
+/// 每次从文件读取的字节数
+const CHUNK_SIZE: usize = 48 * 1024;
+
+/// 上传过程中出现的错误，请求失败后优先返回这里记录的原因
+type Failure = Arc<Mutex<Option<VxwkError>>>;
+
+fn fail(failure: &Failure, err: VxwkError) {
+    if let Ok(mut slot) = failure.lock() {
+        slot.get_or_insert(err);
+    }
+}
+
+/// 第一次读取时才用 `tokio::fs` 打开的文件，每次发出请求都重新打开
+pub(crate) enum LazyFile {
+    Opening(Pin<Box<dyn Future<Output = io::Result<File>> + Send>>),
+    Open(File),
+}
+
+impl LazyFile {
+    pub(crate) fn new(path: PathBuf) -> Self {
+        Self::Opening(Box::pin(File::open(path)))
+    }
+}
+
+impl AsyncRead for LazyFile {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        loop {
+            match this {
+                LazyFile::Opening(open) => match open.as_mut().poll(cx) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Err(err)) => return Poll::Ready(Err(err)),
+                    Poll::Ready(Ok(file)) => *this = LazyFile::Open(file),
+                },
+                LazyFile::Open(file) => return Pin::new(file).poll_read(cx, buf),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Prefix,
+    Data,
+    Suffix,
+    Done,
+}
+
+/// 请求体 `{"file":"base64:<data>","name":<name>}`，读取多少编码多少
+///
+/// 读满 [`SNIFF_LEN`] 字节后识别文件类型，开启 `content_type` 时在末尾附带 `contentType`
+struct EncodeBody<R> {
+    /// 只用于满足 [`Body::wrap_stream`] 的 `Sync` 要求，读取时不加锁
+    reader: Mutex<R>,
+    name: String,
+    stage: Stage,
+    buf: Vec<u8>,
+    /// 未编码的数据，识别类型前保存文件开头，之后只保存不足 3 字节的部分
+    carry: Vec<u8>,
+    read: u64,
+    file_type: Option<FileType>,
+    opt: UploadOptions,
+    failure: Failure,
+}
+
+impl<R> EncodeBody<R> {
+    fn new(reader: R, name: &str, opt: UploadOptions, failure: Failure) -> Self {
+        Self {
+            reader: Mutex::new(reader),
+            name: name.to_string(),
+            stage: Stage::Prefix,
+            buf: vec![0; CHUNK_SIZE],
+            carry: Vec::with_capacity(SNIFF_LEN),
+            read: 0,
+            file_type: None,
+            opt,
+            failure,
+        }
+    }
+
+    fn sniff(&mut self) -> Result<(), VxwkError> {
+        let mut validator = Validator::new();
+        self.file_type = check_file(&mut validator, &self.carry);
+        validator.finish()
+    }
+
+    fn abort(&mut self, err: VxwkError) -> Poll<Option<io::Result<Vec<u8>>>> {
+        let message = err.to_string();
+        fail(&self.failure, err);
+        self.stage = Stage::Done;
+        Poll::Ready(Some(Err(io::Error::other(message))))
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for EncodeBody<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        match this.stage {
+            Stage::Prefix => {
+                this.stage = Stage::Data;
+                Poll::Ready(Some(Ok(br#"{"file":"base64:"#.to_vec())))
+            }
+            Stage::Data => loop {
+                let reader = match this.reader.get_mut() {
+                    Ok(reader) => reader,
+                    Err(poisoned) => poisoned.into_inner(),
+                };
+                let mut buf = ReadBuf::new(&mut this.buf);
+                let filled = match Pin::new(reader).poll_read(cx, &mut buf) {
+                    Poll::Pending => return Poll::Pending,
+                    Poll::Ready(Err(err)) => return this.abort(VxwkError::IoError(err)),
+                    Poll::Ready(Ok(())) => buf.filled().len(),
+                };
+                if filled == 0 {
+                    if this.file_type.is_none() {
+                        if let Err(err) = this.sniff() {
+                            return this.abort(err);
+                        }
+                    }
+                    this.stage = Stage::Suffix;
+                    let tail = STANDARD.encode(&this.carry);
+                    this.carry.clear();
+                    return Poll::Ready(Some(Ok(tail.into_bytes())));
+                }
+                this.read += filled as u64;
+                if this.read > this.opt.max_size {
+                    return this.abort(too_large(this.opt.max_size));
+                }
+                this.carry.extend_from_slice(&this.buf[..filled]);
+                if this.file_type.is_none() {
+                    if this.carry.len() < SNIFF_LEN {
+                        continue;
+                    }
+                    if let Err(err) = this.sniff() {
+                        return this.abort(err);
+                    }
+                }
+                let whole = this.carry.len() / 3 * 3;
+                let encoded = STANDARD.encode(&this.carry[..whole]);
+                this.carry.drain(..whole);
+                return Poll::Ready(Some(Ok(encoded.into_bytes())));
+            },
+            Stage::Suffix => {
+                this.stage = Stage::Done;
+                let name = serde_json::Value::String(this.name.clone());
+                let mut suffix = format!(r#"","name":{}"#, name);
+                if let (true, Some(file_type)) = (this.opt.content_type, this.file_type) {
+                    suffix.push_str(&format!(r#","contentType":"{}""#, file_type.mime()));
+                }
+                suffix.push('}');
+                Poll::Ready(Some(Ok(suffix.into_bytes())))
+            }
+            Stage::Done => Poll::Ready(None),
+        }
+    }
+}
+
+impl VxwkAPI {
+    /// 流式上传文件，`open` 在每次发出请求时调用一次，切换节点重试时数据无法再次读取则返回错误
+    pub(crate) async fn upload_stream<R, O>(
+        &self,
+        name: &str,
+        opt: UploadOptions,
+        open: O,
+    ) -> Result<ApiResponse<UploadedFile>, VxwkError>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+        O: Fn() -> io::Result<R>,
+    {
+        let mut validator = Validator::new();
+        validator.required("name", name);
+        validator.finish()?;
+
+        let failure = Failure::default();
+        let result = self
+            .send(LiveCodeFileResource::CREATE_PATH, HashMap::new(), |url| {
+                let body = match open() {
+                    Ok(reader) => {
+                        Body::wrap_stream(EncodeBody::new(reader, name, opt, failure.clone()))
+                    }
+                    Err(err) => {
+                        let message = err.to_string();
+                        fail(&failure, VxwkError::IoError(err));
+                        Body::wrap_stream(futures::stream::once(async move {
+                            Err::<Vec<u8>, _>(io::Error::other(message))
+                        }))
+                    }
+                };
+                self.client
+                    .post(url)
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(body)
+            })
+            .await;
+        let (response, meta) = match result {
+            Ok(sent) => sent,
+            Err(err) => {
+                let reason = failure.lock().ok().and_then(|mut slot| slot.take());
+                return Err(reason.unwrap_or(err));
+            }
+        };
+        ApiResponse::decode(response.json().await?, meta)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{EncodeBody, Failure};
+    use crate::model::livecodefile::{encode_file, UploadOptions};
+    use crate::{VxwkAPI, VxwkConfig, VxwkError};
+    use futures::TryStreamExt;
+
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+    async fn encode(data: &[u8], opt: UploadOptions) -> (Result<Vec<u8>, std::io::Error>, Failure) {
+        let failure = Failure::default();
+        let mut body = EncodeBody::new(data, "图.png", opt, failure.clone());
+        // 每次只读取几个字节，覆盖跨块编码
+        body.buf.truncate(4);
+        let chunks: Result<Vec<Vec<u8>>, _> = body.try_collect().await;
+        (chunks.map(|c| c.concat()), failure)
+    }
+
+    #[tokio::test]
+    async fn test_stream_matches_full_encoding() {
+        let mut data = PNG.to_vec();
+        data.extend((0..=255).cycle().take(1000));
+        let opt = UploadOptions::new().with_content_type(true);
+        let (body, _) = encode(&data, opt).await;
+        let body: serde_json::Value = serde_json::from_slice(&body.unwrap()).unwrap();
+        assert_eq!(body["file"], encode_file(&data));
+        assert_eq!(body["name"], "图.png");
+        assert_eq!(body["contentType"], "image/png");
+
+        // 不足识别长度的文件在读完后识别
+        let (body, _) = encode(PNG, UploadOptions::new()).await;
+        let body: serde_json::Value = serde_json::from_slice(&body.unwrap()).unwrap();
+        assert_eq!(body["file"], encode_file(PNG));
+        assert!(body.get("contentType").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reject_oversize_stream() {
+        let opt = UploadOptions::new().with_max_size(8);
+        let (body, failure) = encode(&[0; 10], opt).await;
+        assert!(body.is_err());
+        let reason = failure.lock().unwrap().take();
+        assert!(matches!(reason, Some(VxwkError::Validation(_))));
+
+        let (body, _) = encode(&[], opt).await;
+        assert!(body.is_err());
+
+        let (body, failure) = encode(b"plain text file", UploadOptions::new()).await;
+        assert!(body.is_err());
+        let reason = failure.lock().unwrap().take();
+        assert!(matches!(reason, Some(VxwkError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reader_rejected_before_request() {
+        // 节点不可用，只有在发出请求前拒绝才会得到校验错误
+        let config = VxwkConfig::new(
+            "key".to_string(),
+            "secret".to_string(),
+            "http://127.0.0.1:9".to_string(),
+        );
+        let api = VxwkAPI::new(config).unwrap();
+        let reader: &'static [u8] = b"plain text file";
+        let err = api
+            .live_code_files()
+            .upload_reader(reader, "a.txt", UploadOptions::new())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, VxwkError::Validation(_)));
+    }
+
+    #[tokio::test]
+    async fn test_path_reopened_on_failover() {
+        let primary = crate::test::serve(|_| ("503 Service Unavailable", "")).await;
+        let backup = crate::test::serve(|_| ("200 OK", r#"{"code":200,"data":{"id":"f1"}}"#)).await;
+        let config = VxwkConfig::new("key".to_string(), "secret".to_string(), primary)
+            .with_endpoint(&backup, 10);
+        let api = VxwkAPI::new(config).unwrap();
+        let path = std::env::temp_dir().join(format!("vxwk-upload-{}.png", std::process::id()));
+        std::fs::write(&path, PNG.repeat(10)).unwrap();
+
+        let res = api
+            .live_code_files()
+            .upload_path(&path, "图.png", UploadOptions::new())
+            .await;
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(res.unwrap().data.id, "f1");
+    }
+}
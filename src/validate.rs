@@ -0,0 +1,172 @@
+//! 请求发送前的本地校验
+use crate::VxwkError;
+use std::fmt;
+
+/// 单个字段的校验错误，`field` 为接口中的字段名
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+pub(crate) fn join(errors: &[FieldError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// 可在发送前校验的请求
+pub trait Validate {
+    /// 校验所有字段，有错误时返回 [`VxwkError::Validation`]
+    fn validate(&self) -> Result<(), VxwkError>;
+}
+
+/// 收集字段错误，最后统一返回
+#[derive(Debug, Default)]
+pub(crate) struct Validator {
+    errors: Vec<FieldError>,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn error(&mut self, field: &str, message: impl Into<String>) {
+        self.errors.push(FieldError {
+            field: field.to_string(),
+            message: message.into(),
+        });
+    }
+
+    #[cfg(any(
+        feature = "dy_card",
+        feature = "wx_card",
+        feature = "external",
+        feature = "live_code",
+        feature = "live_code_file"
+    ))]
+    pub fn required(&mut self, field: &str, value: &str) {
+        if value.trim().is_empty() {
+            self.error(field, "is required");
+        }
+    }
+
+    #[cfg(any(
+        feature = "dy_card",
+        feature = "wx_card",
+        feature = "external",
+        feature = "short_link"
+    ))]
+    pub fn url(&mut self, field: &str, value: &str) {
+        if let Err(err) = url::Url::parse(value) {
+            self.error(field, format!("`{}` is not a valid url: {}", value, err));
+        }
+    }
+
+    #[cfg(any(feature = "external", feature = "short_link"))]
+    /// 按字符数而不是字节数限制长度，中文每个字算 1
+    pub fn max_chars(&mut self, field: &str, value: &str, max: usize) {
+        let len = value.chars().count();
+        if len > max {
+            self.error(
+                field,
+                format!("must be at most {} characters, got {}", max, len),
+            );
+        }
+    }
+
+    #[cfg(any(feature = "external", feature = "short_link"))]
+    pub fn uuid(&mut self, field: &str, value: &str) {
+        if !is_uuid(value) {
+            self.error(field, format!("`{}` is not a valid uuid", value));
+        }
+    }
+
+    /// 开始时间必须早于结束时间，任一为 0 表示不限制
+    #[cfg(feature = "external")]
+    pub fn period(&mut self, start_at: i64, stop_at: i64) {
+        if start_at != 0 && stop_at != 0 && start_at >= stop_at {
+            self.error(
+                "startAt",
+                format!("must be earlier than stopAt ({} >= {})", start_at, stop_at),
+            );
+        }
+    }
+
+    pub fn finish(self) -> Result<(), VxwkError> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(VxwkError::Validation(self.errors))
+        }
+    }
+}
+
+/// 校验 8-4-4-4-12 格式的十六进制 UUID
+#[cfg(any(feature = "external", feature = "short_link"))]
+fn is_uuid(value: &str) -> bool {
+    let groups: Vec<_> = value.split('-').collect();
+    groups.len() == 5
+        && groups
+            .iter()
+            .zip([8, 4, 4, 4, 12])
+            .all(|(group, len)| group.len() == len && group.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+// 用到的校验规则在开启 `external` 时全部可用
+#[cfg(all(test, feature = "external"))]
+mod test {
+    use super::{is_uuid, Validator};
+    use crate::VxwkError;
+
+    #[test]
+    fn test_collect_all_errors() {
+        let mut validator = Validator::new();
+        validator.required("title", " ");
+        validator.url("link", "not a url");
+        match validator.finish() {
+            Err(VxwkError::Validation(errors)) => {
+                let fields: Vec<_> = errors.iter().map(|e| e.field.as_str()).collect();
+                assert_eq!(fields, ["title", "link"]);
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_max_chars_counts_chars() {
+        let mut validator = Validator::new();
+        // 20 个汉字占 60 字节，但只算 20 个字符
+        validator.max_chars("title", &"活".repeat(20), 20);
+        assert!(validator.finish().is_ok());
+
+        let mut validator = Validator::new();
+        validator.max_chars("title", &"活".repeat(21), 20);
+        assert!(validator.finish().is_err());
+    }
+
+    #[test]
+    fn test_uuid_and_period() {
+        assert!(is_uuid("8f1d2c3e-0a1b-4c2d-8e3f-0123456789ab"));
+        assert!(!is_uuid("8f1d2c3e0a1b4c2d8e3f0123456789ab"));
+        assert!(!is_uuid("8f1d2c3e-0a1b-4c2d-8e3f-0123456789ag"));
+
+        let mut validator = Validator::new();
+        validator.period(0, 100);
+        validator.period(100, 0);
+        assert!(validator.finish().is_ok());
+
+        let mut validator = Validator::new();
+        validator.period(200, 100);
+        assert!(validator.finish().is_err());
+    }
+}
@@ -0,0 +1,206 @@
+//! 微信卡片相关api
+use crate::batch::BatchOptions;
+use crate::model::wxcard::{CreateWxCard, UpdateWxCard, WxCard, WxCardListFilter};
+use crate::model::{with_id, ApiResponse, Page};
+use crate::paginate::{ListStream, PageOptions};
+use crate::resource::{self, Resource, WxCardResource};
+use crate::{VxwkAPI, VxwkError};
+use serde::Serialize;
+use std::collections::HashMap;
+
+impl VxwkAPI {
+    /// 微信卡片图片地址
+    #[deprecated(note = "use `wx_cards().img_url()` instead")]
+    pub async fn wx_card_img_url(
+        &self,
+        id: &str,
+        opt: Option<HashMap<&str, &str>>,
+    ) -> Result<String, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("projectid", id);
+        if let Some(opt_map) = opt {
+            // 如果有传入的Option参数，将其合并到query_params中
+            query_params.extend(opt_map);
+        }
+        let result = self.get("/api/v1/user/wxcard/img", query_params).await?;
+        let file_url = result.headers().get("Location");
+        if let Some(file_url) = file_url {
+            return Ok(file_url.to_str().unwrap().to_string());
+        }
+        Err(VxwkError::InvalidRequest("get url fail".into()))
+    }
+    /// 微信卡片详情
+    #[deprecated(note = "use `wx_cards().list()` instead")]
+    pub async fn wx_card_list(
+        &self,
+        opt_map: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let result = self.get("/api/v1/user/wxcard", opt_map).await?;
+        Ok(result.json().await?)
+    }
+    /// 创建微信卡片
+    #[deprecated(note = "use `wx_cards().create()` instead")]
+    pub async fn wx_card_create(
+        &self,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let result = self.post("/api/v1/user/wxcard", &opt).await?;
+        Ok(result)
+    }
+    /// 更新微信卡片
+    #[deprecated(note = "use `wx_cards().update()` instead")]
+    pub async fn wx_card_update(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self
+            .post("/api/v1/user/wxcard/update", &query_params)
+            .await?;
+        Ok(result)
+    }
+    /// 删除微信卡片
+    #[deprecated(note = "use `wx_cards().delete()` instead")]
+    pub async fn wx_card_delete(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self
+            .post("/api/v1/user/wxcard/delete", &query_params)
+            .await?;
+        Ok(result)
+    }
+
+    /// 获取微信卡片详情
+    #[deprecated(note = "use `wx_cards().get()` instead")]
+    pub async fn wx_card_info(
+        &self,
+        id: &str,
+        opt: HashMap<&str, &str>,
+    ) -> Result<serde_json::Value, VxwkError> {
+        let mut query_params = HashMap::new();
+        query_params.insert("id", id);
+        query_params.extend(opt);
+        let result = self.get("/api/v1/user/cardwx", query_params).await?;
+        Ok(result.json().await?)
+    }
+
+    /// 微信卡片接口
+    pub fn wx_cards(&self) -> WxCards<'_> {
+        WxCards { api: self }
+    }
+}
+
+/// 微信卡片接口，通过 [`VxwkAPI::wx_cards`] 获取
+#[derive(Clone, Copy)]
+pub struct WxCards<'a> {
+    api: &'a VxwkAPI,
+}
+
+impl<'a> WxCards<'a> {
+    /// 微信卡片列表，按类型化的筛选条件查询
+    pub async fn list(
+        &self,
+        filter: &WxCardListFilter,
+    ) -> Result<ApiResponse<Page<WxCard>>, VxwkError> {
+        resource::list::<WxCardResource>(self.api, filter).await
+    }
+
+    /// 按筛选条件逐页拉取全部微信卡片，返回按需请求的流
+    ///
+    /// `filter` 中的分页字段会被 `opt` 覆盖
+    pub fn stream(self, filter: WxCardListFilter, opt: PageOptions) -> ListStream<'a, WxCard> {
+        resource::stream::<WxCardResource>(self.api, filter, opt)
+    }
+
+    /// 微信卡片详情
+    pub async fn get(&self, id: &str) -> Result<ApiResponse<WxCard>, VxwkError> {
+        resource::get::<WxCardResource>(self.api, id).await
+    }
+
+    /// 创建微信卡片，发送前校验字段
+    pub async fn create(&self, req: &CreateWxCard) -> Result<ApiResponse<WxCard>, VxwkError> {
+        resource::create::<WxCardResource>(self.api, req).await
+    }
+
+    /// 更新微信卡片，发送前校验字段，只发送 `req` 中设置过的字段
+    pub async fn update(&self, id: &str, req: &UpdateWxCard) -> Result<ApiResponse<()>, VxwkError> {
+        resource::update::<WxCardResource>(self.api, id, req).await
+    }
+
+    /// 删除微信卡片
+    pub async fn delete(&self, id: &str) -> Result<ApiResponse<()>, VxwkError> {
+        resource::delete::<WxCardResource>(self.api, id).await
+    }
+
+    /// 创建微信卡片，请求体可以是任意可序列化的结构或 `serde_json::Value`
+    pub async fn create_json<T>(&self, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api.post(WxCardResource::CREATE_PATH, body).await
+    }
+
+    /// 更新微信卡片，`id` 会合并进请求体，其它字段保留原本的 JSON 类型
+    pub async fn update_json<T>(&self, id: &str, body: &T) -> Result<serde_json::Value, VxwkError>
+    where
+        T: Serialize + ?Sized,
+    {
+        self.api
+            .post(WxCardResource::UPDATE_PATH, &with_id(id, body)?)
+            .await
+    }
+
+    /// 微信卡片图片地址
+    pub async fn img_url(&self, id: &str) -> Result<String, VxwkError> {
+        let query_params = HashMap::from([("projectid", id)]);
+        let result = self.api.get("/api/v1/user/wxcard/img", query_params).await?;
+        match result.headers().get("Location") {
+            Some(file_url) => Ok(file_url.to_str()?.to_string()),
+            None => Err(VxwkError::InvalidRequest("get url fail".into())),
+        }
+    }
+
+    /// 批量创建微信卡片，按输入顺序返回每一项的结果
+    pub async fn batch_create(
+        &self,
+        reqs: &[CreateWxCard],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<WxCard>, VxwkError>> {
+        resource::batch_create::<WxCardResource>(self.api, reqs, opt).await
+    }
+
+    /// 批量更新微信卡片，`items` 为 `(id, 更新请求)`
+    pub async fn batch_update<S: AsRef<str>>(
+        &self,
+        items: &[(S, UpdateWxCard)],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        resource::batch_update::<WxCardResource, S>(self.api, items, opt).await
+    }
+
+    /// 批量删除微信卡片
+    pub async fn batch_delete<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<()>, VxwkError>> {
+        resource::batch_delete::<WxCardResource, S>(self.api, ids, opt).await
+    }
+
+    /// 批量获取微信卡片详情
+    pub async fn get_many<S: AsRef<str>>(
+        &self,
+        ids: &[S],
+        opt: BatchOptions,
+    ) -> Vec<Result<ApiResponse<WxCard>, VxwkError>> {
+        resource::get_many::<WxCardResource, S>(self.api, ids, opt).await
+    }
+}